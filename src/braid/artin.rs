@@ -0,0 +1,844 @@
+use std::{
+    collections::{
+        HashMap,
+        hash_map::Entry,
+    },
+    error::Error,
+    f64::consts::PI,
+    fmt,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    rc::Rc,
+};
+
+use crate::braid::{
+    structure::{
+        GarsideStructure,
+        NormalForm,
+    },
+    *,
+};
+
+/*
+ * Spherical Artin groups. A Coxeter matrix m gives the Artin group with
+ * generators s_1, ..., s_r and relations s_i s_j s_i ... = s_j s_i s_j ...
+ * with m_ij letters on each side. When the Coxeter group W it presents is
+ * finite (Brieskorn and Saito, Deligne) the Artin group is a Garside group
+ * whose simple elements are the elements of W, ordered by the weak order,
+ * with the longest element of W as delta. B_n is the case A_(n - 1).
+ *
+ * Elements of W are stored by how they permute the root system of the
+ * geometric representation, which is built once per group in floating
+ * point. This is exact once built, and s_i is a descent of w exactly when
+ * w sends the simple root a_i to a negative root. The Garside structure
+ * only uses the Coxeter group (see CoxeterGroup), so type_b.rs shares it
+ * with signed permutations as elements
+ */
+
+/**
+ * The reasons a matrix can fail to give a spherical Artin group
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoxeterMatrixError {
+    // The matrix is not square, or has no rows
+    NotSquare,
+    // The diagonal entry at this index is not 1
+    BadDiagonal(usize),
+    // The entries at these indices are not equal and at least 2
+    BadEntry(usize, usize),
+    // The Coxeter group is infinite
+    NotSpherical,
+    // There is no Dynkin diagram of this type and rank
+    BadDynkinType,
+}
+
+impl fmt::Display for CoxeterMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotSquare => write!(f, "a Coxeter matrix has to be square and non-empty"),
+            Self::BadDiagonal(i) => write!(f, "diagonal entry {} is not 1", i),
+            Self::BadEntry(i, j) => {
+                write!(f, "entries {}, {} are not symmetric and at least 2", i, j)
+            }
+            Self::NotSpherical => write!(f, "the Coxeter group is infinite"),
+            Self::BadDynkinType => write!(f, "there is no Dynkin diagram of this type"),
+        }
+    }
+}
+
+impl Error for CoxeterMatrixError {}
+
+/**
+ * The connected Dynkin diagrams of finite Coxeter groups, numbered as in
+ * Bourbaki
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynkinType {
+    A(usize),
+    B(usize),
+    D(usize),
+    E6,
+    E7,
+    E8,
+    F4,
+    H3,
+    H4,
+    I2(usize),
+}
+
+/**
+ * A symmetric matrix with 1 on the diagonal and entries at least 2
+ * elsewhere, m_ij being the length of the relation between s_i and s_j.
+ * 0 stands for infinity, which never gives a spherical group
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoxeterMatrix {
+    m: Vec<Vec<usize>>,
+}
+
+impl CoxeterMatrix {
+    pub fn new(m: Vec<Vec<usize>>) -> Result<Self, CoxeterMatrixError> {
+        let r = m.len();
+        if r == 0 || m.iter().any(|row| row.len() != r) {
+            return Err(CoxeterMatrixError::NotSquare);
+        }
+        for (i, row) in m.iter().enumerate() {
+            if row[i] != 1 {
+                return Err(CoxeterMatrixError::BadDiagonal(i));
+            }
+            if let Some(j) = (0..i).find(|j| row[*j] != m[*j][i] || row[*j] == 1) {
+                return Err(CoxeterMatrixError::BadEntry(i, j));
+            }
+        }
+        Ok(Self { m })
+    }
+
+    /**
+     * The matrix of a Dynkin diagram
+     */
+    pub fn from_dynkin(t: DynkinType) -> Result<Self, CoxeterMatrixError> {
+        use DynkinType::*;
+
+        // The edges other than the 2s, with 1-based nodes
+        let (r, edges): (usize, Vec<(usize, usize, usize)>) = match t {
+            A(n) if n >= 1 => (n, (1..n).map(|i| (i, i + 1, 3)).collect()),
+            B(n) if n >= 2 => (
+                n,
+                (1..n).map(|i| (i, i + 1, if i + 1 == n { 4 } else { 3 })).collect(),
+            ),
+            D(n) if n >= 4 => (
+                n,
+                (1..n - 1)
+                    .map(|i| (i, i + 1, 3))
+                    .chain(std::iter::once((n - 2, n, 3)))
+                    .collect(),
+            ),
+            E6 | E7 | E8 => {
+                let r = match t {
+                    E6 => 6,
+                    E7 => 7,
+                    _ => 8,
+                };
+                let chain = std::iter::once((1, 3, 3)).chain((3..r).map(|i| (i, i + 1, 3)));
+                (r, chain.chain(std::iter::once((2, 4, 3))).collect())
+            }
+            F4 => (4, vec![(1, 2, 3), (2, 3, 4), (3, 4, 3)]),
+            H3 => (3, vec![(1, 2, 5), (2, 3, 3)]),
+            H4 => (4, vec![(1, 2, 5), (2, 3, 3), (3, 4, 3)]),
+            I2(m) if m >= 2 => (2, vec![(1, 2, m)]),
+            _ => return Err(CoxeterMatrixError::BadDynkinType),
+        };
+        let mut m = vec![vec![2; r]; r];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = 1;
+        }
+        for (i, j, k) in edges {
+            m[i - 1][j - 1] = k;
+            m[j - 1][i - 1] = k;
+        }
+        Self::new(m)
+    }
+
+    /**
+     * The number of generators
+     */
+    pub fn rank(&self) -> usize {
+        self.m.len()
+    }
+
+    pub fn entry(&self, i: usize, j: usize) -> usize {
+        self.m[i][j]
+    }
+
+    /**
+     * The bilinear form of the geometric representation,
+     * B(a_i, a_j) = -cos(pi / m_ij)
+     */
+    fn bilinear_form(&self) -> Vec<Vec<f64>> {
+        self.m
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&m| if m == 0 { -1.0 } else { -(PI / m as f64).cos() })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /**
+     * Is the Coxeter group finite? Exactly when the bilinear form is
+     * positive definite, which Cholesky decomposition checks
+     * O(r^3)
+     */
+    pub fn is_spherical(&self) -> bool {
+        let b = self.bilinear_form();
+        let r = self.rank();
+        let mut l = vec![vec![0.0; r]; r];
+        for i in 0..r {
+            for j in 0..=i {
+                let s: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+                if i == j {
+                    let d = b[i][i] - s;
+                    if d <= 1e-9 {
+                        return false;
+                    }
+                    l[i][i] = d.sqrt();
+                } else {
+                    l[i][j] = (b[i][j] - s) / l[j][j];
+                }
+            }
+        }
+        true
+    }
+}
+
+/**
+ * An element of a finite Coxeter group, as the permutation it induces on
+ * the roots: the entry at k is the index of the image of root k. Roots
+ * 0..N are the positive ones, starting with the simple roots, and root
+ * k + N is the negative of root k
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CoxeterElement {
+    perm: Vec<usize>,
+}
+
+impl CoxeterElement {
+    fn identity(roots: usize) -> Self {
+        Self {
+            perm: (0..roots).collect(),
+        }
+    }
+
+    /**
+     * The number of positive roots
+     */
+    fn positive_roots(&self) -> usize {
+        self.perm.len() / 2
+    }
+
+    /**
+     * The product self other, which applies other first
+     * O(N)
+     */
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            perm: other.perm.iter().map(|k| self.perm[*k]).collect(),
+        }
+    }
+
+    /**
+     * O(N)
+     */
+    pub fn inverse(&self) -> Self {
+        let mut perm = vec![0; self.perm.len()];
+        for (k, image) in self.perm.iter().enumerate() {
+            perm[*image] = k;
+        }
+        Self { perm }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.perm.iter().enumerate().all(|(k, image)| k == *image)
+    }
+
+    /**
+     * The Coxeter length: the number of positive roots sent to negative ones
+     * O(N)
+     */
+    pub fn length(&self) -> usize {
+        let n = self.positive_roots();
+        self.perm[..n].iter().filter(|k| **k >= n).count()
+    }
+
+    /**
+     * Is self s_i shorter than self, for 0-based i?
+     * O(1)
+     */
+    pub fn is_right_descent(&self, i: usize) -> bool {
+        self.perm[i] >= self.positive_roots()
+    }
+
+    /**
+     * Is s_i self shorter than self, for 0-based i?
+     * O(N)
+     */
+    pub fn is_left_descent(&self, i: usize) -> bool {
+        let k = self.perm.iter().position(|image| *image == i).unwrap();
+        k >= self.positive_roots()
+    }
+}
+
+/**
+ * The roots of a finite Coxeter group and how the generators permute them
+ */
+#[derive(Debug)]
+struct RootSystem {
+    generators: Vec<CoxeterElement>,
+    longest: CoxeterElement,
+}
+
+impl RootSystem {
+    /**
+     * The positive roots are found from the simple ones by reflecting: s_i
+     * permutes the positive roots other than a_i
+     * O(r * N)
+     */
+    fn new(matrix: &CoxeterMatrix) -> Self {
+        let r = matrix.rank();
+        let b = matrix.bilinear_form();
+        let reflect = |i: usize, v: &[f64]| -> Vec<f64> {
+            let c: f64 = 2.0 * (0..r).map(|j| b[i][j] * v[j]).sum::<f64>();
+            let mut res = v.to_vec();
+            res[i] -= c;
+            res
+        };
+        // Coordinates in the basis of simple roots, rounded to look them up
+        let key = |v: &[f64]| -> Vec<i64> { v.iter().map(|x| (x * 1e6).round() as i64).collect() };
+
+        let mut roots: Vec<Vec<f64>> = (0..r)
+            .map(|i| (0..r).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        let mut index: HashMap<Vec<i64>, usize> =
+            roots.iter().enumerate().map(|(k, v)| (key(v), k)).collect();
+        let mut k = 0;
+        while k < roots.len() {
+            for i in 0..r {
+                if k == i {
+                    continue;
+                }
+                let v = reflect(i, &roots[k]);
+                if let Entry::Vacant(e) = index.entry(key(&v)) {
+                    e.insert(roots.len());
+                    roots.push(v);
+                }
+            }
+            k += 1;
+        }
+        let n = roots.len();
+        for k in 0..n {
+            let negative: Vec<f64> = roots[k].iter().map(|x| -x).collect();
+            index.insert(key(&negative), k + n);
+            roots.push(negative);
+        }
+
+        let generators: Vec<CoxeterElement> = (0..r)
+            .map(|i| CoxeterElement {
+                perm: roots.iter().map(|v| index[&key(&reflect(i, v))]).collect(),
+            })
+            .collect();
+
+        // Multiply by non-descents until every generator is a descent
+        let mut longest = CoxeterElement::identity(2 * n);
+        while let Some(i) = (0..r).find(|i| !longest.is_right_descent(*i)) {
+            longest = longest.compose(&generators[i]);
+        }
+
+        Self { generators, longest }
+    }
+}
+
+/**
+ * A finite Coxeter group, given by how to compute with its elements. Its
+ * elements ordered by the weak order are the simple elements of the
+ * Garside structure of its Artin group, so every implementation is also a
+ * GarsideStructure. Generators are numbered from 0
+ */
+pub trait CoxeterGroup: Clone + PartialEq + Eq + Hash + fmt::Debug {
+    type Element: Clone + PartialEq + Eq + Hash + fmt::Debug;
+
+    /**
+     * The number of generators
+     */
+    fn rank(&self) -> usize;
+
+    fn identity_element(&self) -> Self::Element;
+
+    fn longest_element(&self) -> Self::Element;
+
+    /**
+     * The generator s_i
+     */
+    fn simple_reflection(&self, i: usize) -> Self::Element;
+
+    /**
+     * The product a b
+     */
+    fn compose(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    fn inverse(&self, w: &Self::Element) -> Self::Element;
+
+    /**
+     * The Coxeter length
+     */
+    fn length(&self, w: &Self::Element) -> usize;
+
+    /**
+     * Is s_i w shorter than w?
+     */
+    fn is_left_descent(&self, w: &Self::Element, i: usize) -> bool;
+}
+
+impl<C: CoxeterGroup> GarsideStructure for C {
+    type Simple = C::Element;
+
+    fn identity(&self) -> C::Element {
+        self.identity_element()
+    }
+
+    fn delta(&self) -> C::Element {
+        self.longest_element()
+    }
+
+    fn atoms(&self) -> Vec<C::Element> {
+        (0..self.rank()).map(|i| self.simple_reflection(i)).collect()
+    }
+
+    fn simple_len(&self, s: &C::Element) -> usize {
+        self.length(s)
+    }
+
+    /**
+     * The longest element is an involution, so this is conjugation by it
+     */
+    fn tau(&self, s: &C::Element) -> C::Element {
+        let w0 = self.longest_element();
+        self.compose(&self.compose(&w0, s), &w0)
+    }
+
+    fn tau_inverse(&self, s: &C::Element) -> C::Element {
+        self.tau(s)
+    }
+
+    fn tau_order(&self) -> usize {
+        2
+    }
+
+    /**
+     * While some generator is a left descent of both, peel it off both
+     * and append it to the result
+     * O(l * r) descent checks where l is the length of the result
+     */
+    fn left_meet(&self, a: &C::Element, b: &C::Element) -> C::Element {
+        let (mut a, mut b) = (a.clone(), b.clone());
+        let mut res = self.identity_element();
+        while let Some(i) =
+            (0..self.rank()).find(|i| self.is_left_descent(&a, *i) && self.is_left_descent(&b, *i))
+        {
+            let s = self.simple_reflection(i);
+            a = self.compose(&s, &a);
+            b = self.compose(&s, &b);
+            res = self.compose(&res, &s);
+        }
+        res
+    }
+
+    /**
+     * Inversion swaps prefixes and suffixes
+     */
+    fn right_meet(&self, a: &C::Element, b: &C::Element) -> C::Element {
+        self.inverse(&self.left_meet(&self.inverse(a), &self.inverse(b)))
+    }
+
+    fn right_complement(&self, s: &C::Element) -> C::Element {
+        self.compose(&self.inverse(s), &self.longest_element())
+    }
+
+    fn left_complement(&self, s: &C::Element) -> C::Element {
+        self.compose(&self.longest_element(), &self.inverse(s))
+    }
+
+    fn quotient(&self, a: &C::Element, b: &C::Element) -> C::Element {
+        self.compose(&self.inverse(a), b)
+    }
+
+    fn is_identity(&self, s: &C::Element) -> bool {
+        self.length(s) == 0
+    }
+}
+
+/**
+ * The Garside structure of a spherical Artin group, given by its Coxeter
+ * matrix. Groups are equal when their matrices are, and are cheap to clone
+ */
+#[derive(Clone)]
+pub struct ArtinGroup {
+    matrix: CoxeterMatrix,
+    roots: Rc<RootSystem>,
+}
+
+impl PartialEq for ArtinGroup {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix
+    }
+}
+
+impl Eq for ArtinGroup {}
+
+impl Hash for ArtinGroup {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.matrix.hash(state);
+    }
+}
+
+impl fmt::Debug for ArtinGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ArtinGroup")
+            .field("matrix", &self.matrix)
+            .finish()
+    }
+}
+
+impl ArtinGroup {
+    /**
+     * The Artin group of a Coxeter matrix, if its Coxeter group is finite
+     * O(r * N)
+     */
+    pub fn new(matrix: CoxeterMatrix) -> Result<Self, CoxeterMatrixError> {
+        if !matrix.is_spherical() {
+            return Err(CoxeterMatrixError::NotSpherical);
+        }
+        let roots = Rc::new(RootSystem::new(&matrix));
+        Ok(Self { matrix, roots })
+    }
+
+    pub fn from_dynkin(t: DynkinType) -> Result<Self, CoxeterMatrixError> {
+        Self::new(CoxeterMatrix::from_dynkin(t)?)
+    }
+
+    pub fn matrix(&self) -> &CoxeterMatrix {
+        &self.matrix
+    }
+
+    /**
+     * The generator s_i of the Coxeter group, for 1-based i
+     */
+    pub fn generator(&self, i: usize) -> &CoxeterElement {
+        assert!(
+            0 < i && i <= self.rank(),
+            "s_{} is not a generator of a group of rank {}",
+            i,
+            self.rank()
+        );
+        &self.roots.generators[i - 1]
+    }
+
+    /**
+     * A reduced word for an element of the Coxeter group, as 1-based
+     * generator indices
+     * O(l * r * N) where l is the length
+     */
+    pub fn reduced_word(&self, w: &CoxeterElement) -> Vec<usize> {
+        let mut w = w.clone();
+        let mut res = vec![];
+        while let Some(i) = (0..self.rank()).find(|i| w.is_left_descent(*i)) {
+            res.push(i + 1);
+            w = self.roots.generators[i].compose(&w);
+        }
+        res
+    }
+}
+
+impl CoxeterGroup for ArtinGroup {
+    type Element = CoxeterElement;
+
+    fn rank(&self) -> usize {
+        self.matrix.rank()
+    }
+
+    fn identity_element(&self) -> CoxeterElement {
+        CoxeterElement::identity(self.roots.longest.perm.len())
+    }
+
+    fn longest_element(&self) -> CoxeterElement {
+        self.roots.longest.clone()
+    }
+
+    fn simple_reflection(&self, i: usize) -> CoxeterElement {
+        self.roots.generators[i].clone()
+    }
+
+    fn compose(&self, a: &CoxeterElement, b: &CoxeterElement) -> CoxeterElement {
+        a.compose(b)
+    }
+
+    fn inverse(&self, w: &CoxeterElement) -> CoxeterElement {
+        w.inverse()
+    }
+
+    fn length(&self, w: &CoxeterElement) -> usize {
+        w.length()
+    }
+
+    fn is_left_descent(&self, w: &CoxeterElement, i: usize) -> bool {
+        w.is_left_descent(i)
+    }
+}
+
+/**
+ * The left normal form delta^k A_1 ... A_r of an element of a spherical
+ * Artin group
+ */
+pub type ArtinForm = NormalForm<ArtinGroup>;
+
+impl ArtinForm {
+    /**
+     * Multiply on the right by a single generator, Sigma(i) standing for s_i
+     */
+    pub fn push_generator(&mut self, g: BrGen) {
+        match g {
+            BrGen::Sigma(i) => self.push_factor(self.structure.generator(i).clone()),
+            BrGen::SigmaInv(i) => {
+                let s = self.structure.generator(i).clone();
+                self.push_inverse_factor(&s);
+            }
+        }
+    }
+
+    /**
+     * The word of this form: delta^k followed by a reduced word for each
+     * factor. Equal forms always give the same word
+     */
+    pub fn to_artin_braid(&self) -> ArtinBraid {
+        let group = &self.structure;
+        let delta = group.reduced_word(&group.delta());
+        let mut gens: Vec<BrGen> = vec![];
+        for _ in 0..self.delta_exp.unsigned_abs() {
+            if self.delta_exp > 0 {
+                gens.extend(delta.iter().map(|i| BrGen::Sigma(*i)));
+            } else {
+                gens.extend(delta.iter().rev().map(|i| BrGen::SigmaInv(*i)));
+            }
+        }
+        for a in &self.factors {
+            gens.extend(group.reduced_word(a).into_iter().map(BrGen::Sigma));
+        }
+        ArtinBraid {
+            gens,
+            group: group.clone(),
+        }
+    }
+}
+
+/**
+ * An element of a spherical Artin group as a word in the generators, where
+ * Sigma(i) and SigmaInv(i) stand for s_i and its inverse
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtinBraid {
+    pub gens: Vec<BrGen>,
+    pub group: ArtinGroup,
+}
+
+impl std::ops::Mul for ArtinBraid {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        debug_assert_eq!(
+            self.group, other.group,
+            "Attempted to compose elements of different groups!"
+        );
+
+        Self {
+            gens: self.gens.into_iter().chain(other.gens).collect(),
+            group: self.group,
+        }
+    }
+}
+
+impl ArtinBraid {
+    pub fn from_sigmas(sigmas: &[isize], group: &ArtinGroup) -> Self {
+        Self {
+            gens: sigmas.iter().cloned().map(<_>::from).collect(),
+            group: group.clone(),
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            gens: self.gens.iter().rev().map(|g| g.inverse()).collect(),
+            group: self.group.clone(),
+        }
+    }
+
+    /**
+     * The left normal form of this element
+     */
+    pub fn as_normal_form(&self) -> ArtinForm {
+        let mut res = ArtinForm::identity(self.group.clone());
+        for g in &self.gens {
+            res.push_generator(*g);
+        }
+        res
+    }
+
+    /**
+     * Are self and other the same element? Decided by comparing normal forms
+     */
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.group == other.group && self.as_normal_form() == other.as_normal_form()
+    }
+
+    /**
+     * Decide whether other is conjugate to self by searching super summit
+     * sets. If it is, return c with other = c self c^-1, as for braids
+     */
+    pub fn is_conjugate(&self, other: &Self) -> Option<Self> {
+        self.as_normal_form()
+            .conjugator_to(&other.as_normal_form())
+            .map(|c| c.inverse().to_artin_braid())
+    }
+}
+
+impl Braid {
+    /**
+     * The same word in group, if it is the Artin group of type A_(n - 1).
+     * There is none for n <= 1. Taking the group lets one root system be
+     * shared by many braids
+     */
+    pub fn to_artin_braid(&self, group: &ArtinGroup) -> Option<ArtinBraid> {
+        let matrix = self
+            .n
+            .checked_sub(1)
+            .and_then(|r| CoxeterMatrix::from_dynkin(DynkinType::A(r)).ok())?;
+        (*group.matrix() == matrix).then(|| ArtinBraid {
+            gens: self.gens.clone(),
+            group: group.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::braid::type_b::TypeBGen;
+
+    #[test]
+    fn coxeter_matrix_tests() {
+        use DynkinType::*;
+
+        // The number of positive roots of each type
+        for (t, roots) in [
+            (A(4), 10),
+            (B(3), 9),
+            (D(5), 20),
+            (E6, 36),
+            (E7, 63),
+            (E8, 120),
+            (F4, 24),
+            (H3, 15),
+            (H4, 60),
+            (I2(7), 7),
+        ] {
+            let group = ArtinGroup::from_dynkin(t).unwrap();
+            assert_eq!(group.delta().length(), roots);
+        }
+
+        assert_eq!(CoxeterMatrix::from_dynkin(D(3)), Err(CoxeterMatrixError::BadDynkinType));
+        assert_eq!(
+            CoxeterMatrix::new(vec![vec![1, 3], vec![2, 1]]),
+            Err(CoxeterMatrixError::BadEntry(1, 0))
+        );
+        // The affine group of type A_2 is infinite
+        let affine = CoxeterMatrix::new(vec![vec![1, 3, 3], vec![3, 1, 3], vec![3, 3, 1]]).unwrap();
+        assert_eq!(ArtinGroup::new(affine).unwrap_err(), CoxeterMatrixError::NotSpherical);
+        let free = CoxeterMatrix::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+        assert!(!free.is_spherical());
+    }
+
+    #[test]
+    fn artin_relation_tests() {
+        let group = ArtinGroup::from_dynkin(DynkinType::I2(5)).unwrap();
+        let b = |sigmas: &[isize]| ArtinBraid::from_sigmas(sigmas, &group);
+        assert!(b(&[1, 2, 1, 2, 1]).is_equivalent(&b(&[2, 1, 2, 1, 2])));
+        assert!(!b(&[1, 2, 1, 2]).is_equivalent(&b(&[2, 1, 2, 1])));
+        let delta = b(&[1, 2, 1, 2, 1]).as_normal_form();
+        assert_eq!((delta.inf(), delta.canonical_length()), (1, 0));
+
+        let group = ArtinGroup::from_dynkin(DynkinType::H3).unwrap();
+        let b = |sigmas: &[isize]| ArtinBraid::from_sigmas(sigmas, &group);
+        assert!(b(&[1, 3]).is_equivalent(&b(&[3, 1])));
+        assert!(b(&[2, 3, 2]).is_equivalent(&b(&[3, 2, 3])));
+        assert!(b(&[1, -3, 2, -2, 3, -1]).is_equivalent(&b(&[])));
+        let x = b(&[1, 2, -3, 2, 2, -1, 3]);
+        let form = x.as_normal_form();
+        assert!(form.to_artin_braid().is_equivalent(&x));
+        assert_eq!(form.inverse(), x.inverse().as_normal_form());
+    }
+
+    #[test]
+    fn type_a_and_b_tests() {
+        let a3 = ArtinGroup::from_dynkin(DynkinType::A(3)).unwrap();
+        for _ in 0..10 {
+            let x = Braid::random_positive(4, 2, 3, 0.1) * Braid::random_positive(4, 2, 3, 0.1).inverse();
+            let y = Braid::random_positive(4, 2, 3, 0.1) * Braid::random_positive(4, 2, 3, 0.1).inverse();
+            let (ax, ay) = (x.to_artin_braid(&a3).unwrap(), y.to_artin_braid(&a3).unwrap());
+            assert_eq!(ax.is_equivalent(&ay), x.is_equivalent(&y));
+            assert_eq!(ax.as_normal_form().canonical_length(), x.as_garside_form().canonical_length());
+        }
+
+        // Only the group of type A_(n - 1) will do, and there is none for n <= 1
+        assert!(Braid::from_sigmas(&[1], 3).to_artin_braid(&a3).is_none());
+        assert!(Braid::from_sigmas(&[], 1).to_artin_braid(&a3).is_none());
+        assert!(Braid::from_sigmas(&[], 0).to_artin_braid(&a3).is_none());
+
+        // In Bourbaki's numbering node n of B_n is t and node n - i is sigma_i
+        let n = 3;
+        let group = ArtinGroup::from_dynkin(DynkinType::B(n)).unwrap();
+        let to_type_b = |x: &ArtinBraid| {
+            let gens: Vec<TypeBGen> = x
+                .gens
+                .iter()
+                .map(|g| match *g {
+                    BrGen::Sigma(i) if i == n => TypeBGen::T,
+                    BrGen::SigmaInv(i) if i == n => TypeBGen::TInv,
+                    BrGen::Sigma(i) => TypeBGen::Sigma(n - i),
+                    BrGen::SigmaInv(i) => TypeBGen::SigmaInv(n - i),
+                })
+                .collect();
+            crate::braid::type_b::TypeBBraid::from_gens(&gens, n)
+        };
+        for (u, v) in [
+            (&[3, 2, 3, 2][..], &[2, 3, 2, 3][..]),
+            (&[1, 2, -3, 1][..], &[1, -3, 2, 1][..]),
+            (&[1, 3, -2, 1, 2][..], &[3, 1, 1, -2, 2][..]),
+        ] {
+            let (x, y) = (ArtinBraid::from_sigmas(u, &group), ArtinBraid::from_sigmas(v, &group));
+            assert_eq!(x.is_equivalent(&y), to_type_b(&x).is_equivalent(&to_type_b(&y)));
+        }
+    }
+
+    #[test]
+    fn artin_conjugacy_tests() {
+        let group = ArtinGroup::from_dynkin(DynkinType::D(4)).unwrap();
+        let x = ArtinBraid::from_sigmas(&[1, 2, -3, 4, 2], &group);
+        let a = ArtinBraid::from_sigmas(&[2, -4, 1, 3], &group);
+        let y = a.clone() * x.clone() * a.inverse();
+        let c = x.is_conjugate(&y).unwrap();
+        assert!((c.clone() * x.clone() * c.inverse()).is_equivalent(&y));
+        assert!(x.is_conjugate(&ArtinBraid::from_sigmas(&[1, 2, 3, 4, 2], &group)).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{
+        Hash,
+        Hasher,
+    },
+};
+
+use crate::braid::{
+    garside::GarsideForm,
+    *,
+};
+
+/**
+ * A braid compared as an element of B_n rather than as a word.
+ * Two words represent the same group element exactly when their
+ * Garside normal forms agree, so all comparisons go through the normal form
+ */
+pub struct CanonicalBraid {
+    form: GarsideForm,
+}
+
+impl CanonicalBraid {
+    pub fn new(braid: &Braid) -> Self {
+        Self {
+            form: braid.as_garside_form(),
+        }
+    }
+
+    pub fn form(&self) -> &GarsideForm {
+        &self.form
+    }
+
+    /**
+     * The canonical word representing this element
+     */
+    pub fn to_braid(&self) -> Braid {
+        self.form.to_braid()
+    }
+
+    pub fn into_form(self) -> GarsideForm {
+        self.form
+    }
+}
+
+impl From<&Braid> for CanonicalBraid {
+    fn from(braid: &Braid) -> Self {
+        Self::new(braid)
+    }
+}
+
+impl From<Braid> for CanonicalBraid {
+    fn from(braid: Braid) -> Self {
+        Self::new(&braid)
+    }
+}
+
+impl PartialEq for CanonicalBraid {
+    fn eq(&self, other: &Self) -> bool {
+        self.form == other.form
+    }
+}
+
+impl Eq for CanonicalBraid {}
+
+impl Hash for CanonicalBraid {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.form.hash(state);
+    }
+}
+
+impl PartialOrd for CanonicalBraid {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.cmp(other).into()
+    }
+}
+
+impl Ord for CanonicalBraid {
+    /**
+     * Order first by strand count, then by the power of delta,
+     * then lexicographically on the permutation factors
+     */
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.form.n, self.form.delta_exp, &self.form.permutations).cmp(&(
+            other.form.n,
+            other.form.delta_exp,
+            &other.form.permutations,
+        ))
+    }
+}
+
+impl fmt::Display for CanonicalBraid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.form.fmt(f)
+    }
+}
+
+impl Braid {
+    /**
+     * Are self and other the same element of B_n?
+     */
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.n == other.n && CanonicalBraid::new(self) == CanonicalBraid::new(other)
+    }
+
+    pub fn as_canonical(&self) -> CanonicalBraid {
+        CanonicalBraid::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::collections::HashSet,
+    };
+
+    #[test]
+    fn is_equivalent_tests() {
+        let a1 = Braid::from_sigmas(&[2, 1, 2, 1, 2], 3);
+        let a2 = Braid::from_sigmas(&[2, 2, 1, 2, 2], 3);
+        assert!(a1.is_equivalent(&a2));
+        assert_ne!(a1, a2);
+
+        let b1 = Braid::from_sigmas(&[1, 3, -3, 2, 1], 4);
+        let b2 = Braid::from_sigmas(&[2, 1, 2], 4);
+        let b3 = Braid::from_sigmas(&[1, 2, 1, 1], 4);
+        assert!(b1.is_equivalent(&b2));
+        assert!(!b1.is_equivalent(&b3));
+
+        // The same word in a different braid group is a different element
+        let c = Braid::from_sigmas(&[2, 1, 2], 5);
+        assert!(!b2.is_equivalent(&c));
+    }
+
+    #[test]
+    fn canonical_hash_tests() {
+        let words = vec![
+            Braid::from_sigmas(&[1, 2, 1], 3),
+            Braid::from_sigmas(&[2, 1, 2], 3),
+            Braid::from_sigmas(&[1, -1, 1, 2, 1], 3),
+            Braid::from_sigmas(&[1, 2], 3),
+            Braid::from_sigmas(&[], 3),
+            Braid::from_sigmas(&[2, -2], 3),
+        ];
+        let set: HashSet<CanonicalBraid> = words.iter().map(CanonicalBraid::from).collect();
+        assert_eq!(set.len(), 3);
+
+        let mut sorted: Vec<CanonicalBraid> = words.into_iter().map(CanonicalBraid::from).collect();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 3);
+        assert_eq!(format!("{}", sorted[0]), "[0;]");
+        assert_eq!(format!("{}", sorted[2]), "[1;]");
+        assert_eq!(sorted[2].to_braid(), Braid::make_half_twist(3));
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use crate::braid::{
+    garside::GarsideForm,
+    summit::{
+        ConjugacyBackend,
+        SummitGraph,
+    },
+    *,
+};
+
+/*
+ * Centralizers (Franco and Gonzalez-Meneses). Every positive braid
+ * conjugating one element of a super summit set, ultra summit set or set of
+ * sliding circuits to another is a product of minimal simple conjugators, so
+ * it is a path in the graph of that set. Positive elements of the centralizer
+ * of a vertex are then loops at it, and as delta_n^2 is central and positive
+ * every element of the centralizer is one up to a power of delta_n^2, which
+ * is itself a loop. The loops through the arrows outside a spanning tree
+ * therefore generate the centralizer.
+ * Conjugation is x^c = c^-1 x c as in summit.rs
+ */
+
+impl GarsideForm {
+    /**
+     * A finite generating set of the centralizer of self, read off the graph
+     * of the given backend. Generators are distinct and never the identity
+     */
+    pub fn centralizer_with(&self, backend: ConjugacyBackend) -> Vec<Self> {
+        let (y, c) = self.summit_element(backend);
+        SummitGraph::explore(y, Self::summit_arrows(backend), |_| false).centralizer(&c)
+    }
+
+    /**
+     * A finite generating set of the centralizer of self, read off its
+     * sliding circuits
+     */
+    pub fn centralizer(&self) -> Vec<Self> {
+        self.centralizer_with(ConjugacyBackend::default())
+    }
+}
+
+impl SummitGraph {
+    /**
+     * For the complete graph of a conjugate root = c^-1 x c, a finite
+     * generating set of the centralizer of x
+     */
+    pub(crate) fn centralizer(&self, c: &GarsideForm) -> Vec<GarsideForm> {
+        let c_inv = c.inverse();
+        let identity = GarsideForm::identity(c.n());
+
+        // root = c^-1 x c, so z commutes with root exactly when c z c^-1
+        // commutes with x
+        let mut seen = HashSet::new();
+        let mut res: Vec<GarsideForm> = self
+            .loops()
+            .filter(|z| *z != identity)
+            .map(|z| c.clone() * z * c_inv.clone())
+            .filter(|z| seen.insert(z.clone()))
+            .collect();
+        res.sort_by(|a, b| (a.delta_exp, &a.permutations).cmp(&(b.delta_exp, &b.permutations)));
+        res
+    }
+}
+
+impl Braid {
+    /**
+     * A finite generating set of the centralizer of self. Two secret keys a
+     * and a' give the same conjugate of self exactly when a^-1 a' is in it
+     */
+    pub fn centralizer(&self) -> Vec<Self> {
+        self.centralizer_with(ConjugacyBackend::default())
+    }
+
+    /**
+     * centralizer, read off the graph of the given backend
+     */
+    pub fn centralizer_with(&self, backend: ConjugacyBackend) -> Vec<Self> {
+        self.as_garside_form()
+            .centralizer_with(backend)
+            .iter()
+            .map(GarsideForm::to_braid)
+            .collect()
+    }
+
+    /**
+     * Is self other = other self?
+     */
+    pub fn commutes_with(&self, other: &Self) -> bool {
+        (self.clone() * other.clone()).is_equivalent(&(other.clone() * self.clone()))
+    }
+
+    /**
+     * Does self commute with the subgroup generated by
+     * sigma_1 ... sigma_(l - 1)? Conjugating by a secret from that subgroup
+     * then leaves self unchanged
+     */
+    pub fn commutes_with_subgroup(&self, l: usize) -> bool {
+        (1..l.min(self.n)).all(|i| self.commutes_with(&Self::from_sigmas(&[i as isize], self.n)))
+    }
+
+    /**
+     * Is self in the center of B_n, i.e. does it commute with every braid?
+     * For n > 2 these are the powers of delta_n^2
+     */
+    pub fn is_central(&self) -> bool {
+        self.commutes_with_subgroup(self.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centralizer_tests() {
+        let backends = [
+            ConjugacyBackend::SuperSummit,
+            ConjugacyBackend::UltraSummit,
+            ConjugacyBackend::SlidingCircuits,
+        ];
+        for (word, n) in [
+            (&[1][..], 3),
+            (&[1][..], 4),
+            (&[1, 2, 1, 1][..], 4),
+            (&[1, 2, -3, 2, 2][..], 4),
+            (&[1, 2, 3, 4, -1, 2, 2, -3][..], 5),
+        ] {
+            let x = Braid::from_sigmas(word, n);
+            for backend in backends {
+                let gens = x.centralizer_with(backend);
+                assert!(!gens.is_empty());
+                assert!(gens.iter().all(|z| z.commutes_with(&x)));
+            }
+        }
+
+        // The centralizer of sigma_1 in B_4 is generated by sigma_1, sigma_3
+        // and sigma_2 sigma_1^2 sigma_2, which are not all central, and the
+        // last of these is not in the subgroup generated by the others
+        let loop_around = Braid::from_sigmas(&[2, 1, 1, 2], 4);
+        for backend in backends {
+            let gens = Braid::from_sigmas(&[1], 4).centralizer_with(backend);
+            assert!(gens.iter().any(|z| !z.is_central()));
+            for z in [Braid::from_sigmas(&[1], 4), Braid::from_sigmas(&[3], 4), loop_around.clone()] {
+                assert!(gens.iter().any(|g| g.is_equivalent(&z)));
+            }
+        }
+
+        // Every braid commutes with delta_n^2, so the generators of its
+        // centralizer generate B_n
+        let gens = Braid::make_half_twist_power(4, 2).centralizer();
+        assert!(gens.iter().any(|z| !z.is_central()));
+    }
+
+    #[test]
+    fn degenerate_tests() {
+        assert!(Braid::make_half_twist_power(5, 2).is_central());
+        assert!(!Braid::make_half_twist(5).is_central());
+        assert!(Braid::make_half_twist(2).is_central());
+        assert!(!Braid::from_sigmas(&[1, 2], 5).is_central());
+
+        // sigma_4 commutes with sigma_1 and sigma_2 but not with sigma_3
+        let x = Braid::from_sigmas(&[4], 5);
+        assert!(x.commutes_with_subgroup(3));
+        assert!(!x.commutes_with_subgroup(4));
+        assert!(x.commutes_with(&Braid::from_sigmas(&[1, -2, 1], 5)));
+    }
+}
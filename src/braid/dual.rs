@@ -0,0 +1,645 @@
+use std::fmt;
+
+use crate::{
+    braid::{
+        simple::{
+            compose_permutations,
+            invert_permutation,
+        },
+        structure::{
+            GarsideStructure,
+            NormalForm,
+        },
+        *,
+    },
+    permutation::*,
+};
+
+/*
+ * The dual (Birman-Ko-Lee) Garside structure. B_n is also generated by the
+ * band generators a_ts = (sigma_(t - 1) ... sigma_(s + 1)) sigma_s
+ * (sigma_(t - 1) ... sigma_(s + 1))^-1 for t > s, the half twist of a band
+ * joining strands s and t in front of the others. The Garside element is
+ * delta = sigma_(n - 1) ... sigma_1 = a_(n, n - 1) ... a_(2, 1), with
+ * delta^n = delta_n^2, and the simple elements are the non-crossing
+ * partitions of 1..=n: a block t_k > ... > t_1 is the product
+ * a_(t_k, t_(k - 1)) ... a_(t_2, t_1). Distinct simple elements induce
+ * distinct permutations, whose cycles are the blocks, so products and
+ * quotients that are known to be simple are computed on permutations.
+ * Prefixes and suffixes of a simple element are both its refinements, and
+ * meets are intersections of blocks
+ */
+
+/**
+ * A band generator a_ts or its inverse, with t > s
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BandGen {
+    Band(usize, usize),
+    BandInv(usize, usize),
+}
+
+impl BandGen {
+    pub fn inverse(&self) -> Self {
+        match *self {
+            Self::Band(t, s) => Self::BandInv(t, s),
+            Self::BandInv(t, s) => Self::Band(t, s),
+        }
+    }
+
+    /**
+     * An Artin word for this generator
+     * O(t - s)
+     */
+    pub fn to_braid(&self, n: usize) -> Braid {
+        let (t, s, middle) = match *self {
+            Self::Band(t, s) => (t, s, BrGen::Sigma(s)),
+            Self::BandInv(t, s) => (t, s, BrGen::SigmaInv(s)),
+        };
+        debug_assert!(s < t && t <= n, "a_{}{} is not a generator of B_{}", t, s, n);
+        let gens = (s + 1..t)
+            .rev()
+            .map(BrGen::Sigma)
+            .chain(std::iter::once(middle))
+            .chain((s + 1..t).map(BrGen::SigmaInv))
+            .collect();
+        Braid { gens, n }
+    }
+}
+
+impl From<BrGen> for BandGen {
+    /**
+     * sigma_i = a_(i + 1, i)
+     */
+    fn from(g: BrGen) -> Self {
+        match g {
+            BrGen::Sigma(i) => Self::Band(i + 1, i),
+            BrGen::SigmaInv(i) => Self::BandInv(i + 1, i),
+        }
+    }
+}
+
+/**
+ * A braid as a word in the band generators
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BandBraid {
+    pub gens: Vec<BandGen>,
+    // Our braid is an element of B_n
+    pub n: usize,
+}
+
+impl std::ops::Mul for BandBraid {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        debug_assert_eq!(
+            self.n, other.n,
+            "Attempted to compose two different sized braids!"
+        );
+
+        Self {
+            gens: self.gens.into_iter().chain(other.gens).collect(),
+            n: self.n,
+        }
+    }
+}
+
+impl BandBraid {
+    pub fn inverse(&self) -> Self {
+        Self {
+            gens: self.gens.iter().rev().map(|g| g.inverse()).collect(),
+            n: self.n,
+        }
+    }
+
+    /**
+     * The Artin word with each band generator expanded
+     * O(L * n)
+     */
+    pub fn to_braid(&self) -> Braid {
+        let gens = self
+            .gens
+            .iter()
+            .flat_map(|g| g.to_braid(self.n).gens)
+            .collect();
+        Braid { gens, n: self.n }
+    }
+
+    /**
+     * The dual left normal form of this braid
+     * O(L * r * n) where r is the canonical length
+     */
+    pub fn as_dual_form(&self) -> DualForm {
+        let mut res = DualForm::identity(DualBraids::new(self.n));
+        for g in &self.gens {
+            res.push_generator(*g);
+        }
+        res
+    }
+}
+
+/**
+ * A simple element of the dual braid monoid: a non-crossing partition of
+ * 1..=n. It is stored as the permutation it induces, in the same convention
+ * as SimpleBraid
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DualSimple {
+    perm: VecPermutation,
+}
+
+impl DualSimple {
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: VecPermutation::id(n),
+        }
+    }
+
+    /**
+     * delta, the partition with a single block
+     */
+    pub fn delta(n: usize) -> Self {
+        Self::from_blocks(n, &[(1..=n).collect()]).unwrap()
+    }
+
+    /**
+     * The band generator a_ts
+     */
+    pub fn band(t: usize, s: usize, n: usize) -> Self {
+        Self::from_blocks(n, &[vec![s, t]]).unwrap()
+    }
+
+    /**
+     * The simple element with the given blocks, if they are disjoint and
+     * non-crossing. Points in no block are singletons
+     * O(n)
+     */
+    pub fn from_blocks(n: usize, blocks: &[Vec<usize>]) -> Option<Self> {
+        let mut label = vec![0; n];
+        let mut perm = VecPermutation::id(n);
+        for (k, block) in blocks.iter().enumerate() {
+            let mut block = block.clone();
+            block.sort_unstable();
+            for &t in &block {
+                if t == 0 || t > n || label[t - 1] != 0 {
+                    return None;
+                }
+                label[t - 1] = k + 1;
+            }
+            // The strand ending at t_(j + 1) started at t_j
+            for j in 0..block.len() {
+                perm[block[(j + 1) % block.len()] - 1] = block[j];
+            }
+        }
+        let res = Self { perm };
+        res.is_noncrossing().then_some(res)
+    }
+
+    /**
+     * The blocks of the partition, each in increasing order, ordered by
+     * their smallest points
+     * O(n)
+     */
+    pub fn blocks(&self) -> Vec<Vec<usize>> {
+        let n = self.n();
+        let mut seen = vec![false; n];
+        let mut res = vec![];
+        for first in 1..=n {
+            if seen[first - 1] {
+                continue;
+            }
+            let mut block = vec![];
+            let mut t = first;
+            while !seen[t - 1] {
+                seen[t - 1] = true;
+                block.push(t);
+                t = self.perm[t - 1];
+            }
+            block.sort_unstable();
+            res.push(block);
+        }
+        res
+    }
+
+    /**
+     * Checks that no two blocks a < b < c < d cross with a, c in one and
+     * b, d in the other: scanning the points, each one which is not the
+     * first of its block has to continue the innermost open block
+     * O(n)
+     */
+    fn is_noncrossing(&self) -> bool {
+        let n = self.n();
+        let mut first = vec![0; n];
+        let mut last = vec![0; n];
+        for block in self.blocks() {
+            for &t in &block {
+                first[t - 1] = block[0];
+                last[t - 1] = *block.last().unwrap();
+            }
+        }
+        let mut open = vec![];
+        for t in 1..=n {
+            if first[t - 1] == t {
+                open.push(t);
+            } else if open.last() != Some(&first[t - 1]) {
+                return false;
+            }
+            if last[t - 1] == t {
+                open.pop();
+            }
+        }
+        true
+    }
+
+    pub fn n(&self) -> usize {
+        self.perm.len()
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.perm.is_identity()
+    }
+
+    pub fn is_delta(&self) -> bool {
+        *self == Self::delta(self.n())
+    }
+
+    /**
+     * The number of band generators in any positive word for it
+     * O(n)
+     */
+    pub fn len(&self) -> usize {
+        self.n() - self.blocks().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.is_identity()
+    }
+
+    /**
+     * The band word a_(t_k, t_(k - 1)) ... a_(t_2, t_1) of each block
+     * O(n)
+     */
+    pub fn to_band_braid(&self) -> BandBraid {
+        let gens = self
+            .blocks()
+            .iter()
+            .flat_map(|block| {
+                block
+                    .windows(2)
+                    .rev()
+                    .map(|w| BandGen::Band(w[1], w[0]))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        BandBraid { gens, n: self.n() }
+    }
+
+    /**
+     * delta^-1 self delta, which rotates every block by one point
+     * O(n)
+     */
+    pub fn tau(&self) -> Self {
+        let delta = Self::delta(self.n()).perm;
+        let perm = compose_permutations(&invert_permutation(&delta), &self.perm);
+        Self {
+            perm: compose_permutations(&perm, &delta),
+        }
+    }
+
+    /**
+     * delta self delta^-1
+     * O(n)
+     */
+    pub fn tau_inverse(&self) -> Self {
+        let delta = Self::delta(self.n()).perm;
+        let perm = compose_permutations(&delta, &self.perm);
+        Self {
+            perm: compose_permutations(&perm, &invert_permutation(&delta)),
+        }
+    }
+
+    /**
+     * The simple element c with self c = delta, the Kreweras complement
+     * O(n)
+     */
+    pub fn right_complement(&self) -> Self {
+        Self {
+            perm: compose_permutations(&invert_permutation(&self.perm), &Self::delta(self.n()).perm),
+        }
+    }
+
+    /**
+     * The simple element c with c self = delta
+     * O(n)
+     */
+    pub fn left_complement(&self) -> Self {
+        Self {
+            perm: compose_permutations(&Self::delta(self.n()).perm, &invert_permutation(&self.perm)),
+        }
+    }
+
+    /**
+     * The greatest common prefix of self and other, whose blocks are the
+     * intersections of their blocks
+     * O(n^2)
+     */
+    pub fn meet(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.n(), other.n());
+        let mut blocks = vec![];
+        for a in self.blocks() {
+            for b in other.blocks() {
+                let block: Vec<usize> = a.iter().filter(|t| b.contains(t)).cloned().collect();
+                if block.len() > 1 {
+                    blocks.push(block);
+                }
+            }
+        }
+        Self::from_blocks(self.n(), &blocks).unwrap()
+    }
+
+    /**
+     * Is self a prefix of other, i.e. is it a refinement of other?
+     * O(n^2)
+     */
+    pub fn is_left_divisor_of(&self, other: &Self) -> bool {
+        self.meet(other) == *self
+    }
+}
+
+/**
+ * The dual Garside structure on B_n, whose simple elements are the
+ * non-crossing partitions and whose atoms are the band generators
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DualBraids {
+    pub n: usize,
+}
+
+impl DualBraids {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl GarsideStructure for DualBraids {
+    type Simple = DualSimple;
+
+    fn identity(&self) -> DualSimple {
+        DualSimple::identity(self.n)
+    }
+
+    fn delta(&self) -> DualSimple {
+        DualSimple::delta(self.n)
+    }
+
+    fn atoms(&self) -> Vec<DualSimple> {
+        (1..=self.n)
+            .flat_map(|t| (1..t).map(move |s| DualSimple::band(t, s, self.n)))
+            .collect()
+    }
+
+    fn simple_len(&self, s: &DualSimple) -> usize {
+        s.len()
+    }
+
+    fn tau(&self, s: &DualSimple) -> DualSimple {
+        s.tau()
+    }
+
+    fn tau_inverse(&self, s: &DualSimple) -> DualSimple {
+        s.tau_inverse()
+    }
+
+    fn tau_order(&self) -> usize {
+        self.n
+    }
+
+    fn left_meet(&self, a: &DualSimple, b: &DualSimple) -> DualSimple {
+        a.meet(b)
+    }
+
+    fn right_meet(&self, a: &DualSimple, b: &DualSimple) -> DualSimple {
+        a.meet(b)
+    }
+
+    fn right_complement(&self, s: &DualSimple) -> DualSimple {
+        s.right_complement()
+    }
+
+    fn left_complement(&self, s: &DualSimple) -> DualSimple {
+        s.left_complement()
+    }
+
+    fn quotient(&self, a: &DualSimple, b: &DualSimple) -> DualSimple {
+        DualSimple {
+            perm: compose_permutations(&invert_permutation(&a.perm), &b.perm),
+        }
+    }
+
+    fn is_identity(&self, s: &DualSimple) -> bool {
+        s.is_identity()
+    }
+}
+
+/**
+ * The dual left normal form delta^k A_1 ... A_r of a braid, where each A_i
+ * is a non-crossing partition other than the identity and delta, and each
+ * A_i A_i+1 is left weighted
+ */
+pub type DualForm = NormalForm<DualBraids>;
+
+impl fmt::Display for DualForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let factors: Vec<String> = self
+            .factors
+            .iter()
+            .map(|a| {
+                a.blocks()
+                    .iter()
+                    .filter(|block| block.len() > 1)
+                    .map(|block| format!("{:?}", block).replace("[", "(").replace("]", ")"))
+                    .collect()
+            })
+            .collect();
+        write!(f, "[{};{}]", self.delta_exp, factors.join(", "))
+    }
+}
+
+impl DualForm {
+    pub fn n(&self) -> usize {
+        self.structure.n
+    }
+
+    /**
+     * The band word of this form: delta^k followed by the word of each
+     * factor. Equal forms always give the same word
+     */
+    pub fn to_band_braid(&self) -> BandBraid {
+        let n = self.n();
+        let delta = DualSimple::delta(n).to_band_braid();
+        let delta = if self.delta_exp < 0 {
+            delta.inverse()
+        } else {
+            delta
+        };
+        let gens = (0..self.delta_exp.unsigned_abs())
+            .flat_map(|_| delta.gens.iter().cloned())
+            .chain(self.factors.iter().flat_map(|a| a.to_band_braid().gens))
+            .collect();
+        BandBraid { gens, n }
+    }
+
+    pub fn to_braid(&self) -> Braid {
+        self.to_band_braid().to_braid()
+    }
+
+    /**
+     * Multiply this form on the right by a single band generator
+     * O(r * n^2) worst case where r is the canonical length
+     */
+    pub fn push_generator(&mut self, g: BandGen) {
+        let n = self.n();
+        match g {
+            BandGen::Band(t, s) => self.push_factor(DualSimple::band(t, s, n)),
+            BandGen::BandInv(t, s) => self.push_inverse_factor(&DualSimple::band(t, s, n)),
+        }
+    }
+}
+
+impl Braid {
+    /**
+     * The same braid as a word in band generators
+     */
+    pub fn to_band_braid(&self) -> BandBraid {
+        BandBraid {
+            gens: self.iter().map(|&g| g.into()).collect(),
+            n: self.n,
+        }
+    }
+
+    /**
+     * The dual left normal form of this braid
+     */
+    pub fn as_dual_form(&self) -> DualForm {
+        self.to_band_braid().as_dual_form()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dual_simple_tests() {
+        let n = 6;
+        let delta = DualSimple::delta(n);
+        assert!(delta.to_band_braid().to_braid().is_equivalent(&Braid::from_sigmas(&[5, 4, 3, 2, 1], n)));
+        assert_eq!(delta.len(), n - 1);
+
+        // {1, 3} and {2, 4} cross
+        assert!(DualSimple::from_blocks(n, &[vec![1, 3], vec![2, 4]]).is_none());
+        assert!(DualSimple::from_blocks(n, &[vec![1, 3], vec![1, 4]]).is_none());
+        let a = DualSimple::from_blocks(n, &[vec![1, 4, 6], vec![2, 3]]).unwrap();
+        assert_eq!(a.blocks(), vec![vec![1, 4, 6], vec![2, 3], vec![5]]);
+        let word = a.to_band_braid().to_braid();
+        assert!((1..=n).all(|t| word.position(t) == a.permutation().to_vec().position(t)));
+
+        let c = a.right_complement();
+        assert_eq!(c.blocks(), vec![vec![1], vec![2, 4], vec![3], vec![5, 6]]);
+        assert!((a.to_band_braid() * c.to_band_braid())
+            .to_braid()
+            .is_equivalent(&delta.to_band_braid().to_braid()));
+        assert_eq!(a.left_complement().tau(), c);
+
+        // tau rotates blocks, and delta^-1 a_ts delta = a_(t + 1, s + 1)
+        let band = DualSimple::band(3, 1, n);
+        assert_eq!(band.tau(), DualSimple::band(4, 2, n));
+        assert_eq!(DualSimple::band(6, 2, n).tau(), DualSimple::band(3, 1, n));
+        assert_eq!(band.tau().tau_inverse(), band);
+        let conjugate = delta.to_band_braid().inverse() * band.to_band_braid() * delta.to_band_braid();
+        assert!(conjugate.to_braid().is_equivalent(&band.tau().to_band_braid().to_braid()));
+
+        let b = DualSimple::from_blocks(n, &[vec![1, 2, 3, 4]]).unwrap();
+        assert_eq!(a.meet(&b).blocks(), vec![vec![1, 4], vec![2, 3], vec![5], vec![6]]);
+        assert!(a.meet(&b).is_left_divisor_of(&a));
+        assert!(!a.is_left_divisor_of(&b));
+    }
+
+    #[test]
+    fn band_word_tests() {
+        let x = BandBraid {
+            gens: vec![BandGen::Band(4, 1), BandGen::BandInv(3, 2), BandGen::Band(2, 1)],
+            n: 4,
+        };
+        assert_eq!(
+            x.to_braid(),
+            Braid::from_sigmas(&[3, 2, 1, -2, -3, -2, 1], 4)
+        );
+        let y = Braid::from_sigmas(&[1, -3, 2], 4);
+        assert_eq!(y.to_band_braid().to_braid(), y);
+        assert!((x.clone() * x.inverse()).to_braid().is_equivalent(&Braid::from_sigmas(&[], 4)));
+    }
+
+    #[test]
+    fn dual_form_tests() {
+        let n = 5;
+        // delta is a single power, delta^n is delta_n^2
+        let delta = Braid::from_sigmas(&[4, 3, 2, 1], n).as_dual_form();
+        assert_eq!((delta.inf(), delta.canonical_length()), (1, 0));
+        let full_twist = Braid::make_half_twist_power(n, 2).as_dual_form();
+        assert_eq!((full_twist.inf(), full_twist.canonical_length()), (n as isize, 0));
+
+        // The BKL relations
+        let form = |gens: &[BandGen]| {
+            BandBraid {
+                gens: gens.to_vec(),
+                n,
+            }
+            .as_dual_form()
+        };
+        use BandGen::*;
+        assert_eq!(form(&[Band(5, 4), Band(3, 1)]), form(&[Band(3, 1), Band(5, 4)]));
+        assert_eq!(form(&[Band(4, 1), Band(3, 2)]), form(&[Band(3, 2), Band(4, 1)]));
+        assert_eq!(form(&[Band(4, 3), Band(3, 1)]), form(&[Band(4, 1), Band(4, 3)]));
+        assert_eq!(form(&[Band(4, 3), Band(3, 1)]), form(&[Band(3, 1), Band(4, 1)]));
+        assert_eq!(form(&[Band(4, 3), Band(3, 1)]).canonical_length(), 1);
+        assert_ne!(form(&[Band(4, 2), Band(3, 1)]), form(&[Band(3, 1), Band(4, 2)]));
+
+        for _ in 0..20 {
+            let x = Braid::random_positive(n, 3, 4, 0.1) * Braid::random_positive(n, 2, 4, 0.1).inverse();
+            let y = Braid::random_positive(n, 3, 4, 0.1) * Braid::random_positive(n, 2, 4, 0.1).inverse();
+            let (dx, dy) = (x.as_dual_form(), y.as_dual_form());
+            assert!(dx.to_braid().is_equivalent(&x));
+            assert_eq!(dx == dy, x.is_equivalent(&y));
+            assert_eq!(dx.clone() * dy.clone(), (x.clone() * y.clone()).as_dual_form());
+            assert_eq!(dx.inverse(), x.inverse().as_dual_form());
+            for w in dx.factors().windows(2) {
+                assert!(w[0].right_complement().meet(&w[1]).is_identity());
+            }
+            assert!(dx.factors().iter().all(|a| !a.is_identity() && !a.is_delta()));
+        }
+    }
+
+    #[test]
+    fn dual_super_summit_tests() {
+        let n = 4;
+        for _ in 0..5 {
+            let x = Braid::random_positive(n, 2, 3, 0.1) * Braid::random_positive(n, 1, 3, 0.1).inverse();
+            let a = Braid::random_positive(n, 2, 3, 0.1) * Braid::from_sigmas(&[-2, 1, -3], n);
+            let (dx, dy) = (x.as_dual_form(), (a.inverse() * x.clone() * a).as_dual_form());
+            let c = dx.conjugator_to(&dy).unwrap();
+            assert_eq!(dx.conjugate(&c), dy);
+
+            let sss = dx.super_summit_set();
+            assert!(sss.contains(&dy.super_summit_element().0));
+            assert!(sss.iter().all(|z| z.inf() == sss[0].inf() && z.sup() == sss[0].sup()));
+        }
+        let x = Braid::from_sigmas(&[1, 2], n).as_dual_form();
+        assert!(x.conjugator_to(&Braid::from_sigmas(&[1, 3], n).as_dual_form()).is_none());
+    }
+}
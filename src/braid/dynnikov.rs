@@ -0,0 +1,325 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+};
+
+use crate::braid::*;
+
+/*
+ * Dynnikov coordinates. An integer lamination of the n-punctured disk is
+ * determined by its coordinates (a_1, ..., a_(n - 2), b_1, ..., b_(n - 2)),
+ * and braids act on them by piecewise linear maps in which each generator
+ * only changes one or two pairs (a_i, b_i). Coordinates can grow
+ * exponentially with the length of a braid, so all arithmetic is checked.
+ *
+ * Shifting B_n into B_(n + 2) along the strands 2 ... n + 1, the image of
+ * the lamination with a = 0 and b = 1 determines the braid. It is
+ * sigma-positive exactly when the first non-zero term of
+ * a_1, b_1 - 1, a_2, b_2 - 1, ... of the image is positive (Dehornoy)
+ */
+
+/**
+ * The reasons an action on Dynnikov coordinates can fail
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynnikovError {
+    // A coordinate left the range of i128
+    Overflow,
+}
+
+impl fmt::Display for DynnikovError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "a Dynnikov coordinate overflowed"),
+        }
+    }
+}
+
+impl Error for DynnikovError {}
+
+/**
+ * The numbers Dynnikov coordinates can be computed in: exact checked
+ * integers, or floats for rescaled real laminations
+ */
+pub(crate) trait Coordinate: Copy + PartialOrd {
+    const ZERO: Self;
+
+    fn add(self, other: Self) -> Result<Self, DynnikovError>;
+
+    fn sub(self, other: Self) -> Result<Self, DynnikovError>;
+}
+
+impl Coordinate for i128 {
+    const ZERO: Self = 0;
+
+    fn add(self, other: Self) -> Result<Self, DynnikovError> {
+        self.checked_add(other).ok_or(DynnikovError::Overflow)
+    }
+
+    fn sub(self, other: Self) -> Result<Self, DynnikovError> {
+        self.checked_sub(other).ok_or(DynnikovError::Overflow)
+    }
+}
+
+impl Coordinate for f64 {
+    const ZERO: Self = 0.0;
+
+    fn add(self, other: Self) -> Result<Self, DynnikovError> {
+        let res = self + other;
+        res.is_finite().then_some(res).ok_or(DynnikovError::Overflow)
+    }
+
+    fn sub(self, other: Self) -> Result<Self, DynnikovError> {
+        let res = self - other;
+        res.is_finite().then_some(res).ok_or(DynnikovError::Overflow)
+    }
+}
+
+fn add<T: Coordinate>(x: T, y: T) -> Result<T, DynnikovError> {
+    x.add(y)
+}
+
+fn sub<T: Coordinate>(x: T, y: T) -> Result<T, DynnikovError> {
+    x.sub(y)
+}
+
+fn pos<T: Coordinate>(x: T) -> T {
+    if x > T::ZERO { x } else { T::ZERO }
+}
+
+fn neg<T: Coordinate>(x: T) -> T {
+    if x < T::ZERO { x } else { T::ZERO }
+}
+
+/**
+ * Act by a single generator on the coordinates a and b of a lamination of
+ * the (a.len() + 2)-punctured disk. sigma_1 and sigma_(n - 1) only change
+ * the first and last pair, and sigma_i in between changes pairs i - 1 and i
+ */
+pub(crate) fn act<T: Coordinate>(a: &mut [T], b: &mut [T], g: BrGen) -> Result<(), DynnikovError> {
+    let n = a.len() + 2;
+    let (i, positive) = match g {
+        BrGen::Sigma(i) => (i, true),
+        BrGen::SigmaInv(i) => (i, false),
+    };
+    debug_assert!(0 < i && i < n, "sigma_{} is not a generator of B_{}", i, n);
+
+    if i == 1 || i == n - 1 {
+        let k = if i == 1 { 0 } else { n - 3 };
+        let (x, y) = (a[k], b[k]);
+        // The last pair is the mirror image of the first
+        let inner = |z: T| if i == 1 { pos(z) } else { neg(z) };
+        let outer = |z: T| if i == 1 { neg(z) } else { pos(z) };
+        if positive {
+            let t = sub(x, inner(y))?;
+            a[k] = add(y, outer(t))?;
+            b[k] = sub(T::ZERO, t)?;
+        } else {
+            let t = add(x, inner(y))?;
+            a[k] = sub(inner(t), y)?;
+            b[k] = t;
+        }
+        return Ok(());
+    }
+
+    let (j, k) = (i - 2, i - 1);
+    let (a0, b0, a1, b1) = (a[j], b[j], a[k], b[k]);
+    if positive {
+        let c = add(sub(sub(a0, neg(b0))?, a1)?, pos(b1))?;
+        a[j] = add(add(a0, pos(b0))?, pos(sub(pos(b1), c)?))?;
+        b[j] = sub(b1, pos(c))?;
+        a[k] = add(add(a1, neg(b1))?, neg(add(neg(b0), c)?))?;
+        b[k] = add(b0, pos(c))?;
+    } else {
+        let d = sub(sub(add(a0, neg(b0))?, a1)?, pos(b1))?;
+        a[j] = sub(sub(a0, pos(b0))?, pos(add(pos(b1), d)?))?;
+        b[j] = add(b1, neg(d))?;
+        a[k] = sub(sub(a1, neg(b1))?, neg(sub(neg(b0), d)?))?;
+        b[k] = sub(b0, neg(d))?;
+    }
+    Ok(())
+}
+
+/**
+ * The generator of B_(n + 2) acting like g on the middle n punctures
+ */
+pub(crate) fn shifted(g: BrGen) -> BrGen {
+    match g {
+        BrGen::Sigma(i) => BrGen::Sigma(i + 1),
+        BrGen::SigmaInv(i) => BrGen::SigmaInv(i + 1),
+    }
+}
+
+/**
+ * The Dynnikov coordinates of an integer lamination of the n-punctured disk
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DynnikovCoordinates {
+    a: Vec<i128>,
+    b: Vec<i128>,
+}
+
+impl fmt::Display for DynnikovCoordinates {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}; {:?}", self.a, self.b)
+    }
+}
+
+impl DynnikovCoordinates {
+    /**
+     * The lamination with the given coordinates, for n = a.len() + 2
+     */
+    pub fn new(a: Vec<i128>, b: Vec<i128>) -> Self {
+        assert_eq!(a.len(), b.len(), "Dynnikov coordinates come in pairs");
+        Self { a, b }
+    }
+
+    /**
+     * The lamination of the n-punctured disk with a = 0 and b = 1
+     */
+    pub fn standard(n: usize) -> Self {
+        let m = n.saturating_sub(2);
+        Self::new(vec![0; m], vec![1; m])
+    }
+
+    /**
+     * The number of punctures
+     */
+    pub fn n(&self) -> usize {
+        self.a.len() + 2
+    }
+
+    pub fn a(&self) -> &[i128] {
+        &self.a
+    }
+
+    pub fn b(&self) -> &[i128] {
+        &self.b
+    }
+
+    /**
+     * Act by a single generator
+     */
+    pub fn apply_generator(&mut self, g: BrGen) -> Result<(), DynnikovError> {
+        act(&mut self.a, &mut self.b, g)
+    }
+
+    /**
+     * Act by a braid, one generator at a time from the left
+     * O(len)
+     */
+    pub fn apply(&mut self, braid: &Braid) -> Result<(), DynnikovError> {
+        debug_assert_eq!(self.n(), braid.n);
+        braid.iter().try_for_each(|&g| self.apply_generator(g))
+    }
+
+    /**
+     * The image of self under braid
+     */
+    pub fn image(&self, braid: &Braid) -> Result<Self, DynnikovError> {
+        let mut res = self.clone();
+        res.apply(braid)?;
+        Ok(res)
+    }
+}
+
+impl Braid {
+    /**
+     * The Dynnikov coordinates of the image of the standard lamination of
+     * the (n + 2)-punctured disk under self, acting on the middle n
+     * punctures. Two braids are equal exactly when these are
+     */
+    pub fn dynnikov_coordinates(&self) -> Result<DynnikovCoordinates, DynnikovError> {
+        let mut res = DynnikovCoordinates::standard(self.n + 2);
+        self.iter().try_for_each(|&g| res.apply_generator(shifted(g)))?;
+        Ok(res)
+    }
+
+    /**
+     * Are self and other the same element of B_n? Decided by comparing
+     * Dynnikov coordinates, which takes time linear in the length as long
+     * as the coordinates do not overflow
+     */
+    pub fn is_equivalent_by_dynnikov(&self, other: &Self) -> Result<bool, DynnikovError> {
+        Ok(self.n == other.n && self.dynnikov_coordinates()? == other.dynnikov_coordinates()?)
+    }
+
+    /**
+     * sigma_sign through Dynnikov coordinates
+     */
+    pub fn dynnikov_sigma_sign(&self) -> Result<Ordering, DynnikovError> {
+        let coordinates = self.dynnikov_coordinates()?;
+        for (a, b) in coordinates.a.iter().zip(&coordinates.b) {
+            for x in [*a, sub(*b, 1)?] {
+                if x != 0 {
+                    return Ok(x.cmp(&0));
+                }
+            }
+        }
+        Ok(Ordering::Equal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random_coordinates(n: usize) -> DynnikovCoordinates {
+        let word = Braid::random_positive(n, 2, 3, 0.1) * Braid::random_positive(n, 2, 3, 0.1).inverse();
+        let a = (0..n - 2).map(|i| i as i128 - 1).collect();
+        DynnikovCoordinates::new(a, vec![1; n - 2])
+            .image(&word)
+            .unwrap()
+    }
+
+    #[test]
+    fn relation_tests() {
+        for n in 3..8 {
+            for _ in 0..10 {
+                let x = random_coordinates(n);
+                for i in 1..n as isize {
+                    let image = |word: &[isize]| x.image(&Braid::from_sigmas(word, n)).unwrap();
+                    assert_eq!(image(&[i, -i]), x);
+                    assert_eq!(image(&[-i, i]), x);
+                    if i + 1 < n as isize {
+                        assert_eq!(image(&[i, i + 1, i]), image(&[i + 1, i, i + 1]));
+                    }
+                    if i + 2 < n as isize {
+                        assert_eq!(image(&[i, i + 2]), image(&[i + 2, i]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn word_problem_tests() {
+        let a = Braid::from_sigmas(&[1, 2, 1, -3], 4);
+        let b = Braid::from_sigmas(&[2, 1, 3, -3, 2, -3], 4);
+        assert!(a.is_equivalent_by_dynnikov(&b).unwrap());
+        assert!(!a.is_equivalent_by_dynnikov(&Braid::from_sigmas(&[1, 2, 1], 4)).unwrap());
+        // delta_n^2 is central but not trivial
+        let full_twist = Braid::make_half_twist_power(4, 2);
+        assert!(!full_twist.is_equivalent_by_dynnikov(&Braid::from_sigmas(&[], 4)).unwrap());
+
+        for _ in 0..20 {
+            let x = Braid::random_positive(5, 2, 3, 0.1) * Braid::random_positive(5, 2, 3, 0.1).inverse();
+            let y = Braid::random_positive(5, 2, 3, 0.1) * Braid::random_positive(5, 2, 3, 0.1).inverse();
+            assert_eq!(x.is_equivalent_by_dynnikov(&y).unwrap(), x.is_equivalent(&y));
+            let z = x.as_garside_form().to_braid();
+            assert!(x.is_equivalent_by_dynnikov(&z).unwrap());
+            assert_eq!(x.dynnikov_sigma_sign().unwrap(), x.handle_sigma_sign());
+        }
+    }
+
+    #[test]
+    fn overflow_tests() {
+        // sigma_1 sigma_2^-1 stretches laminations by a factor of about 2.6
+        let word: Vec<isize> = [1, -2].iter().cycle().take(400).cloned().collect();
+        let x = Braid::from_sigmas(&word, 3);
+        assert_eq!(x.dynnikov_coordinates(), Err(DynnikovError::Overflow));
+        assert_eq!(x.sigma_sign(), Ordering::Greater);
+        assert_eq!(x.inverse().sigma_sign(), Ordering::Less);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::braid::{
+    dynnikov::{
+        act,
+        shifted,
+    },
+    *,
+};
+
+/*
+ * Topological entropy. The entropy of a braid is the logarithm of its
+ * dilatation, the growth rate of the lengths of laminations under
+ * iteration: zero for periodic braids, log of the stretch factor for
+ * pseudo-Anosov ones and the largest entropy of the pieces for reducible
+ * ones. The action on Dynnikov coordinates is piecewise linear and
+ * homogeneous, so it can be iterated on floats and rescaled as it grows.
+ * The lamination used is the one which determines the braid (see
+ * dynnikov.rs), so no component of a reducible braid is missed
+ */
+
+/**
+ * When to stop iterating: once two successive growth rates differ by at
+ * most tolerance, or after max_iterations applications of the braid
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntropySettings {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for EntropySettings {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-9,
+            max_iterations: 1000,
+        }
+    }
+}
+
+/**
+ * An estimate of the entropy of a braid
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntropyEstimate {
+    pub entropy: f64,
+    // The number of times the braid was applied
+    pub iterations: usize,
+    // Whether the growth rate settled within the tolerance. If not, entropy
+    // is the average growth rate over all iterations
+    pub converged: bool,
+}
+
+impl EntropyEstimate {
+    /**
+     * The dilatation, e^entropy
+     */
+    pub fn dilatation(&self) -> f64 {
+        self.entropy.exp()
+    }
+}
+
+/**
+ * The largest absolute value of a coordinate
+ */
+fn norm(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().chain(b).fold(0.0, |m, x| m.max(x.abs()))
+}
+
+impl Braid {
+    /**
+     * An estimate of the topological entropy of self
+     */
+    pub fn entropy(&self) -> EntropyEstimate {
+        self.entropy_with(EntropySettings::default())
+    }
+
+    /**
+     * An estimate of the topological entropy of self, iterating until the
+     * growth rate of the lamination converges as given by settings
+     * O(max_iterations * len)
+     */
+    pub fn entropy_with(&self, settings: EntropySettings) -> EntropyEstimate {
+        if self.n < 3 {
+            return EntropyEstimate {
+                entropy: 0.0,
+                iterations: 0,
+                converged: true,
+            };
+        }
+
+        let mut a = vec![0.0; self.n];
+        let mut b = vec![1.0; self.n];
+        // log of the norm of the lamination before rescaling
+        let mut log_norm = 0.0;
+        let mut rate = f64::NAN;
+        for k in 1..=settings.max_iterations {
+            let start = log_norm;
+            for &g in self.iter() {
+                // Floats only overflow past e^709, so rescaling rarely is enough
+                act(&mut a, &mut b, shifted(g)).expect("rescaled coordinates overflowed");
+                let m = norm(&a, &b);
+                if m > 1e100 {
+                    a.iter_mut().chain(b.iter_mut()).for_each(|x| *x /= m);
+                    log_norm += m.ln();
+                }
+            }
+            let m = norm(&a, &b);
+            a.iter_mut().chain(b.iter_mut()).for_each(|x| *x /= m);
+            log_norm += m.ln();
+
+            let previous = rate;
+            rate = log_norm - start;
+            if (rate - previous).abs() <= settings.tolerance {
+                return EntropyEstimate {
+                    entropy: rate.max(0.0),
+                    iterations: k,
+                    converged: true,
+                };
+            }
+        }
+        EntropyEstimate {
+            entropy: (log_norm / settings.max_iterations as f64).max(0.0),
+            iterations: settings.max_iterations,
+            converged: false,
+        }
+    }
+}
+
+/**
+ * The indices of candidates by decreasing entropy, together with their
+ * estimates, for picking the braids which mix the most
+ */
+pub fn rank_by_entropy(candidates: &[Braid]) -> Vec<(usize, EntropyEstimate)> {
+    let mut res: Vec<_> = candidates.iter().map(|x| x.entropy()).enumerate().collect();
+    res.sort_by(|(_, x), (_, y)| y.entropy.total_cmp(&x.entropy));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entropy_tests() {
+        // sigma_1 sigma_2^-1 stretches by the square of the golden ratio
+        let golden = (1.0 + 5f64.sqrt()) / 2.0;
+        let x = Braid::from_sigmas(&[1, -2], 3);
+        let estimate = x.entropy();
+        assert!(estimate.converged);
+        assert!((estimate.dilatation() - golden * golden).abs() < 1e-6);
+
+        // Conjugates have the same entropy, and powers multiply it
+        let a = Braid::from_sigmas(&[2, 2, -1, 2], 3);
+        let y = a.clone() * x.clone() * a.inverse();
+        assert!((y.entropy().entropy - estimate.entropy).abs() < 1e-6);
+        let x3 = x.clone() * x.clone() * x.clone();
+        assert!((x3.entropy().entropy - 3.0 * estimate.entropy).abs() < 1e-6);
+
+        // Periodic and reducible braids without pseudo-Anosov pieces
+        for x in [
+            Braid::make_half_twist(5),
+            Braid::from_sigmas(&[1, 2, 3], 4),
+            Braid::from_sigmas(&[1, 1, -3], 4),
+            Braid::from_sigmas(&[], 4),
+        ] {
+            assert!(x.entropy().entropy < 1e-2);
+        }
+
+        // A reducible braid has the entropy of its pseudo-Anosov piece
+        let z = Braid::from_sigmas(&[1, -2, 4], 5);
+        assert!((z.entropy().entropy - estimate.entropy).abs() < 1e-6);
+
+        // Long enough that the coordinates are rescaled within one pass
+        let long = Braid::from_sigmas(&[1, -2].repeat(300), 3);
+        let long_estimate = long.entropy();
+        assert!(long_estimate.converged);
+        assert!((long_estimate.entropy - 300.0 * estimate.entropy).abs() < 1e-6);
+    }
+
+    #[test]
+    fn rank_by_entropy_tests() {
+        let candidates = [
+            Braid::from_sigmas(&[1, 2], 4),
+            Braid::from_sigmas(&[1, -2, 3, -2], 4),
+            Braid::from_sigmas(&[1, -2], 4),
+        ];
+        let ranked: Vec<usize> = rank_by_entropy(&candidates).iter().map(|(i, _)| *i).collect();
+        assert_eq!(ranked, vec![1, 2, 0]);
+    }
+}
@@ -8,21 +8,328 @@ use std::{
 
 // use BrGen::*;
 use crate::{
-    braid::*,
+    braid::{
+        simple::*,
+        structure::{
+            ClassicalBraids,
+            NormalForm,
+        },
+        *,
+    },
     permutation::*,
 };
 
+/**
+ * The left normal form delta_n^k P_1 ... P_r of a braid, where each P_i is a
+ * permutation braid other than the identity and delta_n, and each P_i P_i+1
+ * is left weighted
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GarsideForm {
+    pub(crate) delta_exp: isize,
+    pub(crate) permutations: Vec<VecPermutation>,
+    // The form is of an element of B_n
+    pub(crate) n: usize,
+}
+
+/**
+ * The right normal form of a braid: delta_n^k followed by a sequence
+ * of right-weighted permutation braids
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RightGarsideForm {
     delta_exp: isize,
     permutations: Vec<VecPermutation>,
+    n: usize,
+}
+
+/**
+ * The symmetric (np-) normal form a^-1 b of a braid, where a and b are
+ * positive braids with no common left divisor other than the identity
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymmetricForm {
+    denominator: GarsideForm,
+    numerator: GarsideForm,
+}
+
+/**
+ * The reasons a sequence of permutations can fail to be a left normal form
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GarsideFormError {
+    // The factor at this index does not have n entries
+    WrongSize(usize),
+    // The factor at this index is not a permutation of 1..=n
+    NotAPermutation(usize),
+    // The factor at this index is the identity or delta_n
+    TrivialFactor(usize),
+    // The factors at this index and the next are not left weighted
+    NotLeftWeighted(usize),
+}
+
+impl fmt::Display for GarsideFormError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WrongSize(i) => write!(f, "factor {} has the wrong size", i),
+            Self::NotAPermutation(i) => write!(f, "factor {} is not a permutation", i),
+            Self::TrivialFactor(i) => write!(f, "factor {} is the identity or delta", i),
+            Self::NotLeftWeighted(i) => {
+                write!(f, "factors {} and {} are not left weighted", i, i + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GarsideFormError {}
+
+fn fmt_factors(f: &mut fmt::Formatter, delta_exp: isize, perms: &[VecPermutation]) -> fmt::Result {
+    let vec_string: String = format!("{:?}", perms);
+    let vec_string: String = vec_string[1..vec_string.len() - 1].to_string();
+    let vec_string = vec_string.replace("[", "(").replace("]", ")");
+    write!(f, "[{};{}]", delta_exp, vec_string)
 }
 
 impl fmt::Display for GarsideForm {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let vec_string: String = format!("{:?}", self.permutations);
-        let vec_string: String = vec_string[1..vec_string.len() - 1].to_string();
-        let vec_string = vec_string.replace("[", "(").replace("]", ")");
-        write!(f, "[{};{}]", self.delta_exp, vec_string)
+        fmt_factors(f, self.delta_exp, &self.permutations)
+    }
+}
+
+impl fmt::Display for RightGarsideForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_factors(f, self.delta_exp, &self.permutations)
+    }
+}
+
+/**
+ * Build the braid delta_n^k followed by each of the permutation braids in perms
+ */
+fn factors_to_braid(delta_exp: isize, perms: &[VecPermutation], n: usize) -> Braid {
+    perms
+        .iter()
+        .fold(Braid::make_half_twist_power(n, delta_exp), |acc, p| {
+            acc * Braid::from_slice(&p[..])
+        })
+}
+
+impl GarsideForm {
+    /**
+     * Build a normal form from its parts, checking that every factor is a
+     * permutation of 1..=n other than the identity and delta_n, and that
+     * consecutive factors are left weighted
+     */
+    pub fn from_parts(
+        n: usize,
+        delta_exp: isize,
+        permutations: Vec<VecPermutation>,
+    ) -> Result<Self, GarsideFormError> {
+        let mut braids = Vec::with_capacity(permutations.len());
+        for (i, p) in permutations.iter().enumerate() {
+            if p.len() != n {
+                return Err(GarsideFormError::WrongSize(i));
+            }
+            let mut seen = vec![false; n];
+            for strand in p {
+                if *strand == 0 || *strand > n || seen[*strand - 1] {
+                    return Err(GarsideFormError::NotAPermutation(i));
+                }
+                seen[*strand - 1] = true;
+            }
+            if p.is_identity() || p.is_twist() {
+                return Err(GarsideFormError::TrivialFactor(i));
+            }
+            braids.push(SimpleBraid::from_slice(&p[..]));
+        }
+        for (i, w) in braids.windows(2).enumerate() {
+            if !w[0].finishing_set().is_superset(&w[1].starting_set()) {
+                return Err(GarsideFormError::NotLeftWeighted(i));
+            }
+        }
+
+        Ok(Self {
+            delta_exp,
+            permutations,
+            n,
+        })
+    }
+
+    /**
+     * The form is of an element of B_n
+     */
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /**
+     * The infimum: the largest k such that delta_n^k is a left divisor
+     */
+    pub fn inf(&self) -> isize {
+        self.delta_exp
+    }
+
+    /**
+     * The supremum: the smallest k such that delta_n^k is a right multiple
+     */
+    pub fn sup(&self) -> isize {
+        self.delta_exp + self.canonical_length() as isize
+    }
+
+    /**
+     * The number of non-delta permutation braid factors
+     */
+    pub fn canonical_length(&self) -> usize {
+        self.permutations.len()
+    }
+
+    /**
+     * The permutations of each simple factor, in order
+     */
+    pub fn permutations(&self) -> &[VecPermutation] {
+        &self.permutations
+    }
+
+    /**
+     * The canonical word of this form: delta_n^k followed by each factor
+     * expanded with Braid::from_slice. Equal forms always give the same word
+     */
+    pub fn to_braid(&self) -> Braid {
+        factors_to_braid(self.delta_exp, &self.permutations, self.n)
+    }
+
+    /**
+     * Iterate over the simple factors P_1 ... P_r
+     */
+    pub fn simple_factors(&self) -> impl Iterator<Item = SimpleBraid> + '_ {
+        self.permutations
+            .iter()
+            .map(|p| SimpleBraid::from_slice(&p[..]))
+    }
+
+    /**
+     * Iterate over the simple factors P_1 ... P_r as permutation braids
+     */
+    pub fn factors(&self) -> impl Iterator<Item = Braid> + '_ {
+        self.permutations.iter().map(|p| Braid::from_slice(&p[..]))
+    }
+}
+
+impl GarsideForm {
+    /**
+     * Run f on the same form in the generic classical structure, which
+     * owns the normal form arithmetic (see structure.rs)
+     */
+    fn update(&mut self, f: impl FnOnce(&mut NormalForm<ClassicalBraids>)) {
+        let mut form = NormalForm::from(std::mem::replace(self, Self::identity(self.n)));
+        f(&mut form);
+        *self = form.into();
+    }
+
+    /**
+     * Multiply this form on the right by a permutation braid, keeping it in
+     * left normal form
+     * O(r * n log n) worst case where r is the canonical length, O(n) for
+     * every pair that is already left weighted
+     */
+    pub(crate) fn push_factor(&mut self, factor: VecPermutation) {
+        debug_assert_eq!(factor.len(), self.n);
+        self.update(|form| form.push_factor(SimpleBraid::from_permutation(factor)));
+    }
+
+    /**
+     * Multiply this form on the left by a permutation braid, keeping it in
+     * left normal form
+     * O(r * n log n) worst case where r is the canonical length, O(n) for
+     * every pair that is already left weighted
+     */
+    pub(crate) fn prepend_factor(&mut self, factor: VecPermutation) {
+        debug_assert_eq!(factor.len(), self.n);
+        self.update(|form| form.prepend_factor(SimpleBraid::from_permutation(factor)));
+    }
+
+    /**
+     * Multiply this form on the right by a single generator
+     * O(r * n log n) worst case where r is the canonical length, O(n) for
+     * every pair that is already left weighted
+     */
+    pub fn push_generator(&mut self, g: BrGen) {
+        self.update(|form| form.push_generator(g));
+    }
+
+    /**
+     * Multiply this form on the left by a single generator
+     * O(r * n log n) worst case where r is the canonical length, O(n) for
+     * every pair that is already left weighted
+     */
+    pub fn prepend_generator(&mut self, g: BrGen) {
+        self.update(|form| form.prepend_generator(g));
+    }
+
+    /**
+     * The normal form of the inverse
+     * O(r * n)
+     */
+    pub fn inverse(&self) -> Self {
+        NormalForm::from(self.clone()).inverse().into()
+    }
+}
+
+impl std::ops::Mul for GarsideForm {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        debug_assert_eq!(
+            self.n, other.n,
+            "Attempted to compose two different sized braids!"
+        );
+
+        (NormalForm::from(self) * NormalForm::from(other)).into()
+    }
+}
+
+impl fmt::Display for SymmetricForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}^-1 {}", self.denominator, self.numerator)
+    }
+}
+
+impl SymmetricForm {
+    /**
+     * The positive braid b in a^-1 b
+     */
+    pub fn numerator(&self) -> &GarsideForm {
+        &self.numerator
+    }
+
+    /**
+     * The positive braid a in a^-1 b
+     */
+    pub fn denominator(&self) -> &GarsideForm {
+        &self.denominator
+    }
+}
+
+impl RightGarsideForm {
+    /**
+     * Check that each pair of consecutive factors P_i P_i+1 is right weighted,
+     * that is F(P_i) is a subset of S(P_i+1)
+     */
+    pub fn is_right_weighted(&self) -> bool {
+        let braids: Vec<SimpleBraid> = self
+            .permutations
+            .iter()
+            .map(|p| SimpleBraid::from_slice(&p[..]))
+            .collect();
+        braids
+            .windows(2)
+            .all(|w| w[0].finishing_set().is_subset(&w[1].starting_set()))
+    }
+
+    /**
+     * Compute the (left) Garside normal form of the braid this form represents
+     */
+    pub fn as_garside_form(&self) -> GarsideForm {
+        factors_to_braid(self.delta_exp, &self.permutations, self.n).as_garside_form()
     }
 }
 
@@ -155,92 +462,89 @@ impl Braid {
         res
     }
 
-    // TODO: Re-evaluate O() of as_garside form with workingindex -= 1 change
-    // O(Ln^2 + L^2 + p(L^2 + Ln^2)) where p is the number of permutations which make up self
+    /**
+     * Calculate the left normal form delta_n^k P_1 ... P_r, see
+     * Braid::as_normal_form
+     * O(L * r * n log n) worst case where L is the length of the word and r
+     * the canonical length, and usually far less
+     */
     pub fn as_garside_form(&self) -> GarsideForm {
-        let n = self.n;
-        // O(L*n^2 + L^2)
-        let (exponent, braid) = self.left_slide_delta_form();
-        // O(L)
-        let mut bs = braid.into_permutation();
-        let mut working_index = 0;
-        while working_index < bs.len() - 1 {
-            let mut changed = false;
-            {
-                // Scope for slices of bs
-                // Split bs to take multiple mutable references
-                let (head, tail) = bs.split_at_mut(working_index + 1);
-                let bi = &mut head[working_index];
-                let bi1 = &mut tail[0];
-                // O(L)
-                let mut next_starting = bi1.starting_set();
-                // O(L)
-                let mut prev_finishing = bi.finishing_set();
-                // O(kL + kn^2) where k is the average runtime until a superset is found (probably small)
-                // If we assume k = L for an upper bound, then this is O(L^2 + Ln^2)
-                while !prev_finishing.is_superset(&next_starting) {
-                    {
-                        changed = true;
-                        let j = next_starting.difference(&prev_finishing).next().unwrap();
-                        // j is in S(B_i+1) but not F(B_i)
-                        // bi is easy, just push a sigma on the end
-                        bi.gens.push(BrGen::Sigma(*j));
-                        // bi1 is harder.
-                        // We want to put a sigma -j on the beginning, but we want it to stay positive
-                        // Instead, let's consider bi1 as a permutation with j and j + 1 switched
-                        let mut perm: Vec<usize> = (1..=n).collect();
-                        perm[*j - 1] = *j + 1;
-                        perm[*j] = *j;
-                        // TODO: O(?)
-                        bi1.permute(&mut perm);
-
-                        // Now, we turn it back into a permutation braid
-                        // O(n^2)
-                        let pb = Braid::from_slice(&perm[..]);
-                        // and replace bi1 with it
-                        bi1.gens = pb.gens.clone();
-                    } // For j to go out of scope (j borrows bi1 and bi)
-                    // O(L)
-                    next_starting = bi1.starting_set();
-                    // O(L)
-                    prev_finishing = bi.finishing_set();
+        self.as_normal_form().into()
+    }
+
+    /**
+     * Calculate the right normal form delta_n^k R_1 ... R_r with each
+     * R_i R_i+1 right weighted. Uses the word reversal anti-automorphism:
+     * if rev(b) = delta_n^k Q_1 ... Q_r in left normal form, then
+     * b = rev(Q_r) ... rev(Q_1) delta_n^k = delta_n^k tau^k(rev(Q_r)) ... tau^k(rev(Q_1))
+     */
+    pub fn as_right_garside_form(&self) -> RightGarsideForm {
+        let GarsideForm {
+            delta_exp,
+            permutations,
+            n,
+        } = self.reversed().as_garside_form();
+        let permutations = permutations
+            .iter()
+            .rev()
+            .map(|p| {
+                let rev = invert_permutation(p);
+                if delta_exp % 2 == 0 {
+                    rev
+                } else {
+                    tau_permutation(&rev)
                 }
-            } // Slices of bs go out of scope
-            if bs[working_index + 1].is_identity() {
-                // bi1 is the identity permutation
-                // don't add it
-                bs.remove(working_index + 1);
-            }
-            if changed && working_index != 0 {
-                // Go back to check if the starting set of this was
-                working_index -= 1;
-            } else {
-                working_index += 1;
-            }
+            })
+            .collect();
+
+        RightGarsideForm {
+            delta_exp,
+            permutations,
+            n,
         }
+    }
 
-        // Recombine bs
-        // New: remove intial factors of Delta
-        let mut delta_exp = exponent;
-        let mut result: Vec<VecPermutation> = Vec::new();
-        for braid in &mut bs {
-            let perm = braid.as_vec();
-            if perm.is_twist() {
-                delta_exp += 1;
-            } else {
-                result.push(perm);
-            }
+    /**
+     * Calculate the symmetric normal form a^-1 b with a, b positive and
+     * left-gcd(a, b) = 1.
+     * If self = delta_n^-m P_1 ... P_r in left normal form with m > 0, then
+     * the gcd of delta_n^m and P_1 ... P_r is P_1 ... P_s where s = min(m, r).
+     * Cancelling it gives a = (P_1 ... P_s)^-1 delta_n^m and b = P_s+1 ... P_r
+     */
+    pub fn as_symmetric_form(&self) -> SymmetricForm {
+        let n = self.n;
+        let form = self.as_garside_form();
+        if form.delta_exp >= 0 {
+            return SymmetricForm {
+                denominator: GarsideForm {
+                    delta_exp: 0,
+                    permutations: vec![],
+                    n,
+                },
+                numerator: form,
+            };
         }
 
-        GarsideForm {
-            delta_exp,
-            permutations: result,
+        let m = form.delta_exp.unsigned_abs();
+        let s = m.min(form.permutations.len());
+        let (common, rest) = form.permutations.split_at(s);
+        let denominator = (factors_to_braid(0, common, n).inverse()
+            * Braid::make_half_twist_power(n, m as isize))
+        .as_garside_form();
+
+        SymmetricForm {
+            denominator,
+            numerator: GarsideForm {
+                delta_exp: 0,
+                permutations: rest.to_vec(),
+                n,
+            },
         }
     }
 
     pub fn is_left_weighted(&self) -> bool {
         let ps = self.into_permutation();
-        for i in 0..ps.len() - 1 {
+        for i in 0..ps.len().saturating_sub(1) {
             if !ps[i].finishing_set().is_superset(&ps[i + 1].starting_set()) {
                 return false;
             }
@@ -266,7 +570,7 @@ mod tests {
         let w = Braid::from_sigmas(&[1, -3, 2], 4);
         let lsdf = w.left_slide_delta_form();
         assert_eq!(-1, lsdf.0);
-        let expected = Braid::from_sigmas(&vec![3, 3, 2, 1, 3, 2, 2], 4);
+        let expected = Braid::from_sigmas(&[3, 3, 2, 1, 3, 2, 2], 4);
         assert_eq!(expected.gens, lsdf.1.gens);
     }
 
@@ -277,7 +581,7 @@ mod tests {
         assert_eq!(ps[0].gens, Braid::from_sigmas(&[1, 2], 3).gens);
         assert_eq!(ps[1].gens, Braid::from_sigmas(&[2, 1, 2], 3).gens);
 
-        let p = vec![1, 3, 7, 2, 5, 4, 6];
+        let p = [1, 3, 7, 2, 5, 4, 6];
         let b = Braid::from_slice(&p[..]);
         let old_gens = b.gens.clone();
         let ps = b.into_permutation();
@@ -345,4 +649,253 @@ mod tests {
             format!("{}", a3.as_garside_form())
         );
     }
+
+    #[test]
+    fn right_garside_form_tests() {
+        let b = Braid::from_sigmas(&[1, -3, 2], 4);
+        let rform = b.as_right_garside_form();
+        assert!(rform.is_right_weighted());
+        assert_eq!(
+            format!("{}", rform.as_garside_form()),
+            format!("{}", b.as_garside_form())
+        );
+
+        // Positive braids stay positive with the same number of factors
+        let b = Braid::from_sigmas(&[1, 2, 2, 1, 2], 3);
+        let rform = b.as_right_garside_form();
+        assert_eq!(rform.delta_exp, b.as_garside_form().delta_exp);
+        assert!(rform.is_right_weighted());
+
+        // The trivial braid and pure powers of delta
+        let e = Braid::from_sigmas(&[], 3);
+        assert_eq!(format!("{}", e.as_right_garside_form()), "[0;]");
+        let d = Braid::make_half_twist_power(4, -2);
+        assert_eq!(format!("{}", d.as_right_garside_form()), "[-2;]");
+    }
+
+    #[test]
+    fn right_garside_form_agrees_tests() {
+        for _ in 0..5 {
+            let b = Braid::random_positive(6, 4, 4, 0.1)
+                * Braid::random_positive(6, 3, 4, 0.1).inverse()
+                * Braid::random_positive(6, 2, 4, 0.1);
+            let rform = b.as_right_garside_form();
+            assert!(rform.is_right_weighted());
+            assert_eq!(
+                format!("{}", rform.as_garside_form()),
+                format!("{}", b.as_garside_form())
+            );
+        }
+    }
+
+    #[test]
+    fn symmetric_form_tests() {
+        // sigma_1^-1 sigma_2 is already in symmetric form
+        let b = Braid::from_sigmas(&[-1, 2], 3);
+        let sform = b.as_symmetric_form();
+        assert_eq!(format!("{}", sform.denominator()), "[0;(2, 1, 3)]");
+        assert_eq!(format!("{}", sform.numerator()), "[0;(1, 3, 2)]");
+
+        // Positive braids have a trivial denominator
+        let b = Braid::from_sigmas(&[1, 2, 2, 1], 3);
+        let sform = b.as_symmetric_form();
+        assert_eq!(format!("{}", sform.denominator()), "[0;]");
+        assert_eq!(
+            format!("{}", sform.numerator()),
+            format!("{}", b.as_garside_form())
+        );
+
+        // Mostly positive braids with a few inverses
+        for _ in 0..5 {
+            let b = Braid::random_positive(6, 4, 4, 0.1)
+                * Braid::from_sigmas(&[-2, -4], 6)
+                * Braid::random_positive(6, 2, 4, 0.1);
+            let sform = b.as_symmetric_form();
+            assert!(sform.denominator().delta_exp >= 0);
+            assert!(sform.numerator().delta_exp >= 0);
+            // The denominator can only be made of the two inverses
+            assert!(sform.denominator().permutations.len() <= 2);
+
+            // left-gcd(a, b) = 1 means their starting sets are disjoint
+            let starting = |g: &GarsideForm| match g.permutations.first() {
+                Some(p) if g.delta_exp == 0 => Braid::from_slice(&p[..]).starting_set(),
+                Some(_) => (1..6).collect(),
+                None => IndexSet::new(),
+            };
+            assert!(
+                starting(sform.denominator())
+                    .is_disjoint(&starting(sform.numerator()))
+            );
+
+            let recombined = sform.denominator().to_braid().inverse() * sform.numerator().to_braid();
+            assert_eq!(
+                format!("{}", recombined.as_garside_form()),
+                format!("{}", b.as_garside_form())
+            );
+        }
+    }
+
+    #[test]
+    fn garside_form_accessor_tests() {
+        let gform = Braid::from_sigmas(&[1, -3, 2], 4).as_garside_form();
+        assert_eq!(gform.n(), 4);
+        assert_eq!(gform.inf(), -1);
+        assert_eq!(gform.canonical_length(), 2);
+        assert_eq!(gform.sup(), 1);
+
+        let factors: Vec<Braid> = gform.factors().collect();
+        assert_eq!(factors[0].as_vec(), gform.permutations()[0]);
+        assert_eq!(factors[1].gens, Braid::from_sigmas(&[1, 2], 4).gens);
+
+        let copy = gform.clone();
+        assert_eq!(copy, gform);
+        assert_ne!(copy, Braid::from_sigmas(&[1, 2], 4).as_garside_form());
+    }
+
+    #[test]
+    fn from_parts_tests() {
+        let gform = Braid::from_sigmas(&[1, -3, 2], 4).as_garside_form();
+        assert_eq!(
+            GarsideForm::from_parts(4, -1, gform.permutations().to_vec()),
+            Ok(gform)
+        );
+
+        // sigma_1 sigma_2 followed by itself is not left weighted, but
+        // sigma_2 sigma_1 followed by sigma_1 sigma_2 is
+        let p1 = Braid::from_sigmas(&[1, 2], 3).as_vec();
+        let p2 = Braid::from_sigmas(&[2, 1], 3).as_vec();
+        assert_eq!(
+            GarsideForm::from_parts(3, 0, vec![p1.clone(), p1.clone()]),
+            Err(GarsideFormError::NotLeftWeighted(0))
+        );
+        assert!(GarsideForm::from_parts(3, 0, vec![p2.clone(), p1.clone()]).is_ok());
+
+        assert_eq!(
+            GarsideForm::from_parts(3, 2, vec![p1.clone(), vec![3, 2, 1]]),
+            Err(GarsideFormError::TrivialFactor(1))
+        );
+        assert_eq!(
+            GarsideForm::from_parts(3, 0, vec![p1.clone(), vec![1, 1, 2]]),
+            Err(GarsideFormError::NotAPermutation(1))
+        );
+        assert_eq!(
+            GarsideForm::from_parts(4, 0, vec![p1]),
+            Err(GarsideFormError::WrongSize(0))
+        );
+    }
+
+    #[test]
+    fn to_braid_tests() {
+        let gform = Braid::from_sigmas(&[1, -3, 2], 4).as_garside_form();
+        let expected = Braid::make_half_twist(4).inverse()
+            * Braid::from_slice(&Braid::from_sigmas(&[2, 1, 3, 2, 1], 4).as_vec())
+            * Braid::from_slice(&Braid::from_sigmas(&[1, 2], 4).as_vec());
+        assert_eq!(gform.to_braid(), expected);
+
+        let mut braids = vec![
+            Braid::from_sigmas(&[], 4),
+            Braid::from_sigmas(&[-1], 2),
+            Braid::make_half_twist_power(5, -3),
+            Braid::make_half_twist_power(5, 2) * Braid::from_sigmas(&[1, -4], 5),
+        ];
+        for _ in 0..5 {
+            braids.push(
+                Braid::random_positive(6, 3, 4, 0.1).inverse() * Braid::random_positive(6, 3, 4, 0.1),
+            );
+        }
+        for b in braids {
+            let gform = b.as_garside_form();
+            assert_eq!(gform.to_braid().as_garside_form(), gform);
+        }
+    }
+
+    #[test]
+    fn garside_form_mul_inverse_tests() {
+        let x = Braid::from_sigmas(&[1, -3, 2], 4);
+        let y = Braid::from_sigmas(&[2, 3, 1, -1, -2, 3, 3], 4);
+        assert_eq!(
+            x.as_garside_form() * y.as_garside_form(),
+            (x.clone() * y.clone()).as_garside_form()
+        );
+        assert_eq!(
+            x.as_garside_form().inverse(),
+            x.inverse().as_garside_form()
+        );
+
+        // Multiplying by delta_n and the identity
+        let delta = Braid::make_half_twist(4);
+        assert_eq!(
+            x.as_garside_form() * delta.as_garside_form(),
+            (x.clone() * delta).as_garside_form()
+        );
+        let e = Braid::from_sigmas(&[], 4).as_garside_form();
+        assert_eq!(x.as_garside_form() * e.clone(), x.as_garside_form());
+        assert_eq!(x.as_garside_form() * x.as_garside_form().inverse(), e);
+
+        for _ in 0..5 {
+            let s = Braid::random_positive(6, 3, 4, 0.1).inverse() * Braid::random_positive(6, 3, 4, 0.1);
+            let p = Braid::random_positive(6, 4, 4, 0.1);
+            let (sf, pf) = (s.as_garside_form(), p.as_garside_form());
+            assert_eq!(sf.inverse(), s.inverse().as_garside_form());
+            assert_eq!(
+                sf.clone() * pf * sf.inverse(),
+                (s.clone() * p * s.inverse()).as_garside_form()
+            );
+        }
+    }
+
+    #[test]
+    fn push_prepend_generator_tests() {
+        let e = Braid::from_sigmas(&[], 5).as_garside_form();
+        let mut braids = vec![
+            Braid::from_sigmas(&[1, -3, 2], 5),
+            Braid::from_sigmas(&[-1, -2, -1, 4, 4, 3, -1], 5),
+        ];
+        for _ in 0..5 {
+            braids.push(
+                Braid::random_positive(5, 3, 4, 0.1) * Braid::random_positive(5, 3, 4, 0.1).inverse(),
+            );
+        }
+
+        for b in braids {
+            let expected = b.as_garside_form();
+
+            let mut pushed = e.clone();
+            for g in b.iter() {
+                pushed.push_generator(*g);
+            }
+            assert_eq!(pushed, expected);
+
+            let mut prepended = e.clone();
+            for g in b.iter().rev() {
+                prepended.prepend_generator(*g);
+            }
+            assert_eq!(prepended, expected);
+        }
+    }
+
+    #[test]
+    fn long_word_garside_tests() {
+        // Tens of thousands of generators with a few inverses mixed in
+        let mut b = Braid::random_positive(8, 1500, 8, 0.1);
+        b.mutate(200);
+        assert!(b.gens.len() > 10_000);
+
+        let gform = b.as_garside_form();
+        let identity = Braid::from_sigmas(&[], 8).as_garside_form();
+        assert_eq!((b.clone() * b.inverse()).as_garside_form(), identity);
+        assert_eq!(gform.to_braid().as_garside_form(), gform);
+
+        // Normalising in two halves and multiplying agrees with the whole word
+        let (left, right) = b.gens.split_at(b.gens.len() / 2);
+        let left = Braid {
+            gens: left.to_vec(),
+            n: 8,
+        };
+        let right = Braid {
+            gens: right.to_vec(),
+            n: 8,
+        };
+        assert_eq!(left.as_garside_form() * right.as_garside_form(), gform);
+    }
 }
@@ -0,0 +1,136 @@
+use crate::braid::*;
+
+/*
+ * Dehornoy's handle reduction. A sigma_i-handle is a subword
+ * sigma_i^e v sigma_i^-e where v only contains sigma_j with j > i. It is
+ * equal to v with every sigma_(i + 1)^d replaced by
+ * sigma_(i + 1)^-e sigma_i^d sigma_(i + 1)^e, and replacing it so is a
+ * reduction step. Reducing the handle which ends first always terminates,
+ * in a word without handles: the empty word exactly when the braid is
+ * trivial, and otherwise a word in which the smallest generator only
+ * appears with one sign. Words are handled as signed generator indices
+ */
+
+/**
+ * The first handle to end in word, as the positions of its two ends.
+ * The search for it starts at the end position from
+ * O(len^2)
+ */
+fn first_handle(word: &[isize], from: usize) -> Option<(usize, usize)> {
+    (from..word.len()).find_map(|q| {
+        let i = word[q].abs();
+        // The closest letter before q with an index of at most i
+        let p = word[..q].iter().rposition(|g| g.abs() <= i)?;
+        (word[p] == -word[q]).then_some((p, q))
+    })
+}
+
+/**
+ * Reduce the handle between positions p and q of word
+ */
+fn reduce_handle(word: &mut Vec<isize>, p: usize, q: usize) {
+    let e = word[p].signum();
+    let i = word[p].abs();
+    let mut inner = Vec::with_capacity(q - p);
+    for &g in &word[p + 1..q] {
+        if g.abs() == i + 1 {
+            inner.extend([-e * (i + 1), g.signum() * i, e * (i + 1)]);
+        } else {
+            inner.push(g);
+        }
+    }
+    word.splice(p..=q, inner);
+}
+
+impl Braid {
+    /**
+     * A word for self without handles, found by handle reduction. It is
+     * usually much shorter than self, and empty exactly when self is trivial
+     */
+    pub fn handle_reduced(&self) -> Self {
+        let mut word: Vec<isize> = self.gens.iter().map(|&g| g.into()).collect();
+        let mut from = 0;
+        while let Some((p, q)) = first_handle(&word, from) {
+            reduce_handle(&mut word, p, q);
+            // No handle ends before p: the word up to there is unchanged
+            from = p;
+        }
+        Self::from_sigmas(&word, self.n)
+    }
+
+    /**
+     * self handle reduced if that makes it shorter, and self otherwise
+     */
+    pub fn shortened(&self) -> Self {
+        let reduced = self.handle_reduced();
+        if reduced.gens.len() < self.gens.len() {
+            reduced
+        } else {
+            self.clone()
+        }
+    }
+
+    /**
+     * Does self contain a handle?
+     */
+    pub fn has_handle(&self) -> bool {
+        let word: Vec<isize> = self.gens.iter().map(|&g| g.into()).collect();
+        first_handle(&word, 0).is_some()
+    }
+
+    /**
+     * Are self and other the same element of B_n? Decided by handle
+     * reduction of self other^-1, which is often faster than comparing
+     * normal forms for long words that are not far from reduced
+     */
+    pub fn is_equivalent_by_handles(&self, other: &Self) -> bool {
+        self.n == other.n && (self.clone() * other.inverse()).handle_reduced().gens.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_reduction_tests() {
+        assert!(Braid::from_sigmas(&[1, 2, -2, -1], 3).handle_reduced().gens.is_empty());
+        assert_eq!(
+            Braid::from_sigmas(&[1, 2, -1], 3).handle_reduced(),
+            Braid::from_sigmas(&[-2, 1, 2], 3)
+        );
+        // sigma_2 is not between the ends, so this is not a handle
+        assert!(!Braid::from_sigmas(&[2, 1, -2], 3).has_handle());
+        assert!(!Braid::from_sigmas(&[1, 2, 1, 3], 4).has_handle());
+
+        for _ in 0..10 {
+            let a = Braid::random_positive(5, 3, 4, 0.1) * Braid::random_positive(5, 3, 4, 0.1).inverse();
+            let b = Braid::random_positive(5, 3, 4, 0.1);
+            let x = a.clone() * b.clone() * a.inverse();
+            let reduced = x.handle_reduced();
+            assert!(!reduced.has_handle());
+            assert!(reduced.is_equivalent(&x));
+            assert!((x.clone() * x.inverse()).handle_reduced().gens.is_empty());
+
+            let shortened = x.shortened();
+            assert!(shortened.gens.len() <= x.gens.len());
+            assert!(shortened.is_equivalent(&x));
+        }
+    }
+
+    #[test]
+    fn word_problem_tests() {
+        let braid_relation = Braid::from_sigmas(&[1, 2, 1], 4);
+        assert!(braid_relation.is_equivalent_by_handles(&Braid::from_sigmas(&[2, 1, 2], 4)));
+        assert!(Braid::from_sigmas(&[1, 3], 4).is_equivalent_by_handles(&Braid::from_sigmas(&[3, 1], 4)));
+        assert!(!Braid::from_sigmas(&[1, 2], 4).is_equivalent_by_handles(&Braid::from_sigmas(&[2, 1], 4)));
+
+        for _ in 0..10 {
+            let x = Braid::random_positive(6, 3, 4, 0.1) * Braid::random_positive(6, 2, 4, 0.1).inverse();
+            let y = Braid::random_positive(6, 3, 4, 0.1) * Braid::random_positive(6, 2, 4, 0.1).inverse();
+            assert_eq!(x.is_equivalent_by_handles(&y), x.is_equivalent(&y));
+            let z = x.as_garside_form().to_braid();
+            assert!(x.is_equivalent_by_handles(&z));
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::{
+    cell::Cell,
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    error::Error,
+    fmt,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::braid::{
+    garside::GarsideForm,
+    summit::{
+        ConjugacyBackend,
+        SummitGraph,
+    },
+    *,
+};
+
+/*
+ * Attacking the Ko-Lee key exchange: the public key is x together with
+ * y = a x a^-1 for a secret a in the left half subgroup generated by
+ * sigma_1 ... sigma_(l - 1), l = n / 2. Any a' with y = a' x a'^-1 found
+ * in that subgroup commutes with the other party's secret and so recovers
+ * the shared key. Solving the conjugacy problem gives some a with
+ * y = a x a^-1, and every other is a z for z in the centralizer of x, so
+ * the search goes on through those until one lies in the subgroup. It runs
+ * over one of the conjugacy backends and can be cut short by a budget
+ */
+
+/**
+ * Limits on a conjugacy search. Steps count the cyclings, decyclings and
+ * slidings on the way to the summit elements, the conjugates expanded in
+ * the graph of the chosen backend and the conjugators tried in the left
+ * half subgroup. Both limits are checked before every step
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SearchBudget {
+    pub max_steps: Option<usize>,
+    pub max_time: Option<Duration>,
+}
+
+/**
+ * What a conjugacy search did, whether or not it succeeded
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchStats {
+    pub backend: ConjugacyBackend,
+    // The number of steps taken, as counted by SearchBudget
+    pub steps: usize,
+    // The number of arrows found between the conjugates visited
+    pub arrows: usize,
+    pub elapsed: Duration,
+    // inf, sup and canonical length of the summit elements
+    pub inf: isize,
+    pub sup: isize,
+    pub canonical_length: usize,
+    // Whether x and y were found to be conjugate, even if no conjugator in
+    // the left half subgroup was
+    pub conjugate: bool,
+}
+
+/**
+ * The reasons a conjugacy search can fail
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchError {
+    // The braids are not conjugate
+    NotConjugate,
+    // The budget ran out before a conjugator was found
+    BudgetExhausted,
+    // The braids are conjugate, but not by anything in the left half subgroup
+    OutsideSubgroup,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotConjugate => write!(f, "the braids are not conjugate"),
+            Self::BudgetExhausted => write!(f, "the search budget ran out"),
+            Self::OutsideSubgroup => write!(f, "no conjugator lies in the left half subgroup"),
+        }
+    }
+}
+
+impl Error for SearchError {}
+
+/**
+ * The outcome of a search together with its statistics
+ */
+#[derive(Debug, Clone)]
+pub struct SearchReport {
+    pub result: Result<Braid, SearchError>,
+    pub stats: SearchStats,
+}
+
+/**
+ * Counts the steps of a search against its budget
+ */
+struct Meter {
+    budget: SearchBudget,
+    start: Instant,
+    steps: Cell<usize>,
+}
+
+impl Meter {
+    fn new(budget: SearchBudget) -> Self {
+        Self {
+            budget,
+            start: Instant::now(),
+            steps: Cell::new(0),
+        }
+    }
+
+    fn exhausted(&self) -> bool {
+        self.budget.max_steps.is_some_and(|m| self.steps.get() >= m)
+            || self.budget.max_time.is_some_and(|t| self.start.elapsed() >= t)
+    }
+
+    /**
+     * Take a step if the budget allows it. Returns whether it has run out
+     */
+    fn tick(&self) -> bool {
+        if self.exhausted() {
+            return true;
+        }
+        self.steps.set(self.steps.get() + 1);
+        false
+    }
+}
+
+/**
+ * Searches for a' with y = a' x a'^-1 given the public braids x and y
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KoLeeSolver {
+    pub backend: ConjugacyBackend,
+    pub budget: SearchBudget,
+}
+
+impl KoLeeSolver {
+    pub fn new(backend: ConjugacyBackend, budget: SearchBudget) -> Self {
+        Self { backend, budget }
+    }
+
+    /**
+     * Find a' in the left half subgroup with y = a' x a'^-1. The search
+     * conjugates both into the chosen subset of their conjugacy classes,
+     * explores the whole graph of that subset from x, which gives a
+     * conjugator and the centralizer of x, and then tries the products of
+     * the conjugator with words in the generators of the centralizer, shortest
+     * first. Without a budget this only stops if such an a' exists
+     */
+    pub fn solve(&self, x: &Braid, y: &Braid) -> SearchReport {
+        let meter = Meter::new(self.budget);
+        let mut stats = SearchStats {
+            backend: self.backend,
+            steps: 0,
+            arrows: 0,
+            elapsed: Duration::default(),
+            inf: 0,
+            sup: 0,
+            canonical_length: 0,
+            conjugate: false,
+        };
+        let result = self.search(x, y, &meter, &mut stats);
+        stats.steps = meter.steps.get();
+        stats.elapsed = meter.start.elapsed();
+        SearchReport { result, stats }
+    }
+
+    fn search(
+        &self,
+        x: &Braid,
+        y: &Braid,
+        meter: &Meter,
+        stats: &mut SearchStats,
+    ) -> Result<Braid, SearchError> {
+        if x.n != y.n {
+            return Err(SearchError::NotConjugate);
+        }
+        let (xs, cx) = x
+            .as_garside_form()
+            .summit_element_within(self.backend, || meter.tick())
+            .ok_or(SearchError::BudgetExhausted)?;
+        stats.inf = xs.inf();
+        stats.sup = xs.sup();
+        stats.canonical_length = xs.canonical_length();
+        let (ys, cy) = y
+            .as_garside_form()
+            .summit_element_within(self.backend, || meter.tick())
+            .ok_or(SearchError::BudgetExhausted)?;
+        if xs.inf() != ys.inf() || xs.sup() != ys.sup() {
+            return Err(SearchError::NotConjugate);
+        }
+
+        // Expanding a vertex is a step, and once the budget is out the
+        // remaining vertices get no arrows
+        let arrows = GarsideForm::summit_arrows(self.backend);
+        let graph = SummitGraph::explore(
+            xs,
+            |z| if meter.tick() { vec![] } else { arrows(z) },
+            |_| meter.exhausted(),
+        );
+        stats.arrows = graph.arrow_count();
+
+        // ys = d^-1 xs d, xs = cx^-1 x cx and ys = cy^-1 y cy, so
+        // y = a x a^-1 with a = (cx d cy^-1)^-1
+        let Some(d) = graph.conjugator_from_root(&ys) else {
+            return Err(match meter.exhausted() {
+                true => SearchError::BudgetExhausted,
+                false => SearchError::NotConjugate,
+            });
+        };
+        stats.conjugate = true;
+        let a = (cx.clone() * d.clone() * cy.inverse()).inverse();
+        // Without the whole graph the centralizer may be missing generators
+        if meter.exhausted() {
+            return Err(SearchError::BudgetExhausted);
+        }
+        let centralizer = graph.centralizer(&cx);
+        Self::search_left_half(a, &centralizer, x.n / 2, meter)
+    }
+
+    /**
+     * Breadth first search through a z for z in the group generated by
+     * centralizer, for an element of the subgroup generated by
+     * sigma_1 ... sigma_(l - 1)
+     */
+    fn search_left_half(
+        a: GarsideForm,
+        centralizer: &[GarsideForm],
+        l: usize,
+        meter: &Meter,
+    ) -> Result<Braid, SearchError> {
+        let moves: Vec<GarsideForm> = centralizer
+            .iter()
+            .flat_map(|z| [z.clone(), z.inverse()])
+            .collect();
+        let mut seen = HashSet::from([a.clone()]);
+        let mut queue = VecDeque::from([a]);
+        while let Some(c) = queue.pop_front() {
+            if meter.tick() {
+                return Err(SearchError::BudgetExhausted);
+            }
+            let braid = c.to_braid();
+            if braid.is_in_subgroup(l) {
+                return Ok(braid);
+            }
+            for z in &moves {
+                let next = c.clone() * z.clone();
+                if seen.insert(next.clone()) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(SearchError::OutsideSubgroup)
+    }
+}
+
+impl Braid {
+    /**
+     * Does self lie in the subgroup generated by sigma_1 ... sigma_(l - 1)?
+     * Prefixes of positive words in those generators are again such words,
+     * so the symmetric form of an element of the subgroup is the same in
+     * B_n, and both of its parts only braid the first l strands and leave
+     * the others fixed
+     */
+    pub fn is_in_subgroup(&self, l: usize) -> bool {
+        let form = self.as_symmetric_form();
+        [form.denominator(), form.numerator()].iter().all(|part| {
+            part.delta_exp == 0
+                && part
+                    .permutations()
+                    .iter()
+                    .all(|p| p.iter().enumerate().skip(l).all(|(i, strand)| *strand == i + 1))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn left_half_instance(n: usize) -> (Braid, Braid, Braid) {
+        let x = Braid::random_positive(n, 3, 3, 0.0);
+        let mut a = Braid::random_positive(n / 2, 2, 2, 0.1) * Braid::from_sigmas(&[-1], n / 2);
+        a.n = n;
+        let y = a.clone() * x.clone() * a.inverse();
+        (x, y, a)
+    }
+
+    #[test]
+    fn is_in_subgroup_tests() {
+        assert!(Braid::from_sigmas(&[1, -2, 1, 2], 6).is_in_subgroup(3));
+        assert!(!Braid::from_sigmas(&[1, -2, 3], 6).is_in_subgroup(3));
+        // sigma_3 cancels out
+        assert!(Braid::from_sigmas(&[3, 1, -3, 1], 6).is_in_subgroup(2));
+        // This is sigma_3
+        assert!(!Braid::from_sigmas(&[2, 3, 2, -3, -2], 6).is_in_subgroup(3));
+        assert!(!Braid::make_half_twist(6).is_in_subgroup(3));
+        // Braiding only the right half is not enough either
+        assert!(!Braid::from_sigmas(&[5], 6).is_in_subgroup(3));
+        assert!(!Braid::from_sigmas(&[4, 5, -4], 6).is_in_subgroup(3));
+    }
+
+    #[test]
+    fn solve_tests() {
+        for backend in [ConjugacyBackend::UltraSummit, ConjugacyBackend::SlidingCircuits] {
+            let (x, y, _) = left_half_instance(6);
+            let report = KoLeeSolver::new(backend, SearchBudget::default()).solve(&x, &y);
+            let a = report.result.unwrap();
+            assert!((a.clone() * x.clone() * a.inverse()).is_equivalent(&y));
+            assert!(a.is_in_subgroup(3));
+            assert_eq!(report.stats.backend, backend);
+            assert!(report.stats.conjugate);
+            assert!(report.stats.steps >= 1);
+        }
+
+        let x = Braid::from_sigmas(&[1, 2], 4);
+        let report = KoLeeSolver::default().solve(&x, &Braid::from_sigmas(&[1, 3], 4));
+        assert_eq!(report.result.unwrap_err(), SearchError::NotConjugate);
+    }
+
+    #[test]
+    fn budget_tests() {
+        // The super summit set of a generator of B_8 has 7 elements
+        let x = Braid::from_sigmas(&[1], 8);
+        let y = Braid::from_sigmas(&[3], 8);
+        let budget = SearchBudget {
+            max_steps: Some(2),
+            max_time: None,
+        };
+        let report = KoLeeSolver::new(ConjugacyBackend::SuperSummit, budget).solve(&x, &y);
+        assert_eq!(report.result.unwrap_err(), SearchError::BudgetExhausted);
+        assert_eq!(report.stats.steps, 2);
+
+        // Enough steps to get through
+        let budget = SearchBudget {
+            max_steps: Some(1000),
+            max_time: None,
+        };
+        let report = KoLeeSolver::new(ConjugacyBackend::SuperSummit, budget).solve(&x, &y);
+        assert!(report.result.is_ok());
+        assert!(report.stats.steps <= 1000);
+
+        let budget = SearchBudget {
+            max_steps: None,
+            max_time: Some(Duration::from_secs(60)),
+        };
+        let report = KoLeeSolver::new(ConjugacyBackend::SuperSummit, budget).solve(&x, &y);
+        let a = report.result.unwrap();
+        assert!((a.clone() * x.clone() * a.inverse()).is_equivalent(&y));
+        assert!(a.is_in_subgroup(4));
+
+        // sigma_7 is conjugate to sigma_1, but only by braids moving the right half
+        let budget = SearchBudget {
+            max_steps: Some(1000),
+            max_time: None,
+        };
+        let y = Braid::from_sigmas(&[7], 8);
+        let report = KoLeeSolver::new(ConjugacyBackend::SuperSummit, budget).solve(&x, &y);
+        assert_eq!(report.result.unwrap_err(), SearchError::BudgetExhausted);
+        assert!(report.stats.conjugate);
+        assert_eq!(report.stats.steps, 1000);
+    }
+}
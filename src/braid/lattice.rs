@@ -0,0 +1,225 @@
+use crate::braid::{
+    garside::GarsideForm,
+    simple::SimpleBraid,
+    *,
+};
+
+impl GarsideForm {
+    /**
+     * The normal form of the identity in B_n
+     */
+    pub fn identity(n: usize) -> Self {
+        Self {
+            delta_exp: 0,
+            permutations: vec![],
+            n,
+        }
+    }
+
+    /**
+     * The largest simple prefix of a positive form: delta_n if the
+     * form starts with one, otherwise its first factor
+     */
+    pub(crate) fn head(&self) -> SimpleBraid {
+        debug_assert!(self.delta_exp >= 0);
+        if self.delta_exp > 0 {
+            SimpleBraid::delta(self.n)
+        } else {
+            self.simple_factors()
+                .next()
+                .unwrap_or_else(|| SimpleBraid::identity(self.n))
+        }
+    }
+
+    /**
+     * Is other = self c for a positive braid c?
+     */
+    pub fn is_left_divisor_of(&self, other: &Self) -> bool {
+        (self.inverse() * other.clone()).delta_exp >= 0
+    }
+
+    /**
+     * Is other = c self for a positive braid c?
+     */
+    pub fn is_right_divisor_of(&self, other: &Self) -> bool {
+        (other.clone() * self.inverse()).delta_exp >= 0
+    }
+
+    /**
+     * The greatest common prefix of self and other.
+     * Multiplying on the left by delta_n^-m with m the smaller infimum makes
+     * both positive without changing prefixes. For positive x and y,
+     * gcd(x, y) = s gcd(s^-1 x, s^-1 y) where s is the meet of their heads,
+     * and s is the identity exactly when gcd(x, y) is
+     */
+    pub fn left_gcd(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.n, other.n);
+        let m = self.delta_exp.min(other.delta_exp);
+        let mut x = self.clone();
+        let mut y = other.clone();
+        x.delta_exp -= m;
+        y.delta_exp -= m;
+
+        let mut gcd = Self::identity(self.n);
+        loop {
+            let s = x.head().left_meet(&y.head());
+            if s.is_identity() {
+                break;
+            }
+            let mut s_form = Self::identity(self.n);
+            s_form.push_factor(s.into_vec());
+            let s_inv = s_form.inverse();
+            x = s_inv.clone() * x;
+            y = s_inv * y;
+            gcd = gcd * s_form;
+        }
+
+        gcd.delta_exp += m;
+        gcd
+    }
+
+    /**
+     * The least common multiple of self and other in the prefix order.
+     * Inverting swaps the prefix order for the reversed suffix order, so
+     * this is the inverse of the greatest common suffix of the inverses
+     */
+    pub fn left_lcm(&self, other: &Self) -> Self {
+        self.inverse().right_gcd(&other.inverse()).inverse()
+    }
+
+    /**
+     * The greatest common suffix of self and other.
+     * Reversing words swaps prefixes and suffixes
+     */
+    pub fn right_gcd(&self, other: &Self) -> Self {
+        let x = self.to_braid().reversed().as_garside_form();
+        let y = other.to_braid().reversed().as_garside_form();
+        x.left_gcd(&y).to_braid().reversed().as_garside_form()
+    }
+
+    /**
+     * The least common multiple of self and other in the suffix order
+     */
+    pub fn right_lcm(&self, other: &Self) -> Self {
+        self.inverse().left_gcd(&other.inverse()).inverse()
+    }
+}
+
+impl Braid {
+    /**
+     * The greatest braid which is a prefix of both self and other
+     */
+    pub fn left_gcd(&self, other: &Self) -> Self {
+        self.as_garside_form()
+            .left_gcd(&other.as_garside_form())
+            .to_braid()
+    }
+
+    /**
+     * The greatest braid which is a suffix of both self and other
+     */
+    pub fn right_gcd(&self, other: &Self) -> Self {
+        self.as_garside_form()
+            .right_gcd(&other.as_garside_form())
+            .to_braid()
+    }
+
+    /**
+     * The smallest braid with both self and other as prefixes
+     */
+    pub fn left_lcm(&self, other: &Self) -> Self {
+        self.as_garside_form()
+            .left_lcm(&other.as_garside_form())
+            .to_braid()
+    }
+
+    /**
+     * The smallest braid with both self and other as suffixes
+     */
+    pub fn right_lcm(&self, other: &Self) -> Self {
+        self.as_garside_form()
+            .right_lcm(&other.as_garside_form())
+            .to_braid()
+    }
+
+    /**
+     * Is other = self c for a positive braid c?
+     */
+    pub fn is_left_divisor_of(&self, other: &Self) -> bool {
+        self.as_garside_form()
+            .is_left_divisor_of(&other.as_garside_form())
+    }
+
+    /**
+     * Is other = c self for a positive braid c?
+     */
+    pub fn is_right_divisor_of(&self, other: &Self) -> bool {
+        self.as_garside_form()
+            .is_right_divisor_of(&other.as_garside_form())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_lcm_tests() {
+        let s1 = Braid::from_sigmas(&[1], 3);
+        let s2 = Braid::from_sigmas(&[2], 3);
+        let e = Braid::from_sigmas(&[], 3);
+        let delta = Braid::make_half_twist(3);
+
+        assert!(s1.left_gcd(&s2).is_equivalent(&e));
+        assert!(s1.left_lcm(&s2).is_equivalent(&delta));
+        assert!(s1.right_lcm(&s2).is_equivalent(&delta));
+        assert!(s1.inverse().left_gcd(&s2.inverse()).is_equivalent(&delta.inverse()));
+        assert!(s1.inverse().left_lcm(&s2.inverse()).is_equivalent(&e));
+
+        let a = Braid::from_sigmas(&[1, 2], 4);
+        let b = Braid::from_sigmas(&[1, 3], 4);
+        assert!(a.left_gcd(&b).is_equivalent(&Braid::from_sigmas(&[1], 4)));
+        assert!(a.right_gcd(&b).is_equivalent(&Braid::from_sigmas(&[], 4)));
+
+        let a = Braid::from_sigmas(&[2, 1], 4);
+        let b = Braid::from_sigmas(&[3, 1], 4);
+        assert!(a.right_gcd(&b).is_equivalent(&Braid::from_sigmas(&[1], 4)));
+    }
+
+    #[test]
+    fn divisor_tests() {
+        let a = Braid::from_sigmas(&[1, 2], 4);
+        let b = Braid::from_sigmas(&[1, 2, 3, -1], 4);
+        assert!(Braid::from_sigmas(&[1], 4).is_left_divisor_of(&a));
+        assert!(!Braid::from_sigmas(&[2], 4).is_left_divisor_of(&a));
+        assert!(Braid::from_sigmas(&[2], 4).is_right_divisor_of(&a));
+        assert!(!a.is_left_divisor_of(&b));
+        assert!(a.inverse().is_left_divisor_of(&b));
+
+        for _ in 0..5 {
+            let x = Braid::random_positive(5, 3, 4, 0.1) * Braid::random_positive(5, 2, 4, 0.1).inverse();
+            let y = Braid::random_positive(5, 2, 4, 0.1).inverse() * Braid::random_positive(5, 3, 4, 0.1);
+
+            let g = x.left_gcd(&y);
+            assert!(g.is_left_divisor_of(&x) && g.is_left_divisor_of(&y));
+            // Nothing bigger by one generator divides both
+            for i in 1..5 {
+                let bigger = g.clone() * Braid::from_sigmas(&[i as isize], 5);
+                assert!(!(bigger.is_left_divisor_of(&x) && bigger.is_left_divisor_of(&y)));
+            }
+
+            let l = x.left_lcm(&y);
+            assert!(x.is_left_divisor_of(&l) && y.is_left_divisor_of(&l));
+
+            let g = x.right_gcd(&y);
+            assert!(g.is_right_divisor_of(&x) && g.is_right_divisor_of(&y));
+
+            let l = x.right_lcm(&y);
+            assert!(x.is_right_divisor_of(&l) && y.is_right_divisor_of(&l));
+            for i in 1..5 {
+                let smaller = Braid::from_sigmas(&[-(i as isize)], 5) * l.clone();
+                assert!(!(x.is_right_divisor_of(&smaller) && y.is_right_divisor_of(&smaller)));
+            }
+        }
+    }
+}
@@ -1,5 +1,23 @@
+pub mod artin;
+pub mod canonical;
+pub mod centralizer;
+pub mod dual;
+pub mod dynnikov;
+pub mod entropy;
 pub mod garside;
+pub mod handle;
+pub mod ko_lee;
+pub mod lattice;
+pub mod nielsen_thurston;
+pub mod ordering;
 pub mod random;
+pub mod roots;
+pub mod simple;
+pub mod sliding;
+pub mod structure;
+pub mod summit;
+pub mod type_b;
+pub mod ultra;
 
 // pub use crate::prelude::*;
 
@@ -11,7 +29,10 @@ use {
     indexmap::set::IndexSet,
 };
 
-use crate::permutation::*;
+use crate::{
+    braid::simple::SimpleBraid,
+    permutation::*,
+};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BrGen {
@@ -31,7 +52,7 @@ impl From<BrGen> for isize {
 impl From<isize> for BrGen {
     fn from(s: isize) -> Self {
         match s {
-            ..0 => Self::SigmaInv(s.abs() as _),
+            ..0 => Self::SigmaInv(s.unsigned_abs()),
             0 => panic!("BrGen cannot be 0"),
             1.. => Self::Sigma(s as _),
         }
@@ -114,7 +135,7 @@ impl std::ops::Mul for Braid {
 }
 
 #[inline]
-fn do_swap(fwd: &mut Vec<usize>, inv: &mut Vec<usize>, i: usize) {
+fn do_swap(fwd: &mut [usize], inv: &mut [usize], i: usize) {
     // Swap elements in inv
     inv.swap(i - 1, i);
 
@@ -131,8 +152,14 @@ impl Permutation for Braid {
         self.n as _
     }
 
-    fn swap_(&mut self, _a: usize, _b: usize) {
-        unimplemented!()
+    // Append a word whose permutation swaps positions a and b
+    fn swap_(&mut self, a: usize, b: usize) {
+        let (a, b) = (a.min(b), a.max(b));
+        if a == b {
+            return;
+        }
+        self.gens.extend((a..b).map(BrGen::Sigma));
+        self.gens.extend((a..b - 1).rev().map(BrGen::Sigma));
     }
 
     fn position(&self, x: usize) -> usize {
@@ -159,7 +186,7 @@ impl Permutation for Braid {
     // http://hackage.haskell.org/package/combinat-0.2.8.2/docs/src/Math-Combinat-Groups-Braid.html
     // O(n^2) where n is the length of the permutation
     // Allow many single char names since it's directly adapted from the Haskell source
-    #[allow(many_single_char_names)]
+    #[allow(clippy::many_single_char_names)]
     fn from_slice(perm: &[usize]) -> Self {
         // Assuming that perm is a valid permutation
         let n = perm.len();
@@ -206,6 +233,23 @@ impl Braid {
         Self { gens, n }
     }
 
+    /**
+     * delta_n^k, where delta_n is the half twist. Negative powers are
+     * built out of inverse generators
+     */
+    pub fn make_half_twist_power(n: usize, k: isize) -> Self {
+        let twist = if k < 0 {
+            Self::make_half_twist(n).inverse()
+        } else {
+            Self::make_half_twist(n)
+        };
+        let gens = (0..k.unsigned_abs())
+            .flat_map(|_| twist.gens.iter().cloned())
+            .collect();
+
+        Self { gens, n }
+    }
+
     pub fn iter(&self) -> std::slice::Iter<'_, BrGen> {
         self.gens.iter()
     }
@@ -227,6 +271,17 @@ impl Braid {
         ret
     }
 
+    /**
+     * The braid with the same generators in the opposite order.
+     * This is an anti-automorphism of B_n which fixes delta_n
+     */
+    pub fn reversed(&self) -> Self {
+        Self {
+            gens: self.gens.iter().rev().cloned().collect(),
+            n: self.n,
+        }
+    }
+
     pub fn shift(&mut self) {
         for g in &mut self.gens {
             *g = g.shift(self.n);
@@ -234,30 +289,15 @@ impl Braid {
     }
 
     /**
-     * Find the starting set of a braid. Original algorithm. Assumes
+     * Find the starting set of a braid. Assumes
      * this is a positive permutation braid.
      * NOTE: See Proposition 2.4 (2) of Elrifai Morton for info
+     * O(L + n)
      */
     pub fn starting_set(&self) -> IndexSet<usize> {
-        let n = self.n;
-        let mut res = IndexSet::with_capacity(n);
-        let mut pos = VecPermutation::id(n);
-        // Iterate through each of our generators
-        for g in &self.gens {
-            let BrGen::Sigma(a) = g else {
-                panic!("The braid given was not positive!");
-            };
-            let sa = pos[*a - 1];
-            let sb = pos[*a];
-            if sa == sb + 1 {
-                res.insert(sb);
-            } else if sb == sa + 1 {
-                res.insert(sa);
-            }
-            // swap the strings
-            pos.swap_(*a + 1, *a);
-        }
-        res
+        let mut p = VecPermutation::id(self.n);
+        self.permute(&mut p);
+        SimpleBraid::from_permutation(p).starting_set()
     }
 
     pub fn permute(&self, v: &mut Vec<usize>) {
@@ -267,14 +307,14 @@ impl Braid {
     }
 
     /**
-     * Find the finishing set of a braid. Original algorithm. Assumes
+     * Find the finishing set of a braid. Assumes
      * this is a positive permutation braid.
+     * O(L + n)
      */
     pub fn finishing_set(&self) -> IndexSet<usize> {
         let mut p = VecPermutation::id(self.n);
         self.permute(&mut p);
-        // Iterate through each of our generators
-        (1..self.n).filter(|i| p[*i] < p[*i - 1]).collect()
+        SimpleBraid::from_permutation(p).finishing_set()
     }
 
     pub fn as_vec_ser(&self) -> Vec<u8> {
@@ -293,12 +333,12 @@ mod tests {
     #[test]
     fn permut_tests() {
         // Based off of the Haskell permutationBraid
-        let p = vec![3, 4, 1, 2];
+        let p = [3, 4, 1, 2];
         let b = Braid::from_slice(&p[..]);
         let b2 = Braid::from_sigmas(&[2, 1, 3, 2], 4);
         assert_eq!(b.gens, b2.gens);
 
-        let p = vec![1, 3, 7, 2, 5, 4, 6];
+        let p = [1, 3, 7, 2, 5, 4, 6];
         let b = Braid::from_slice(&p[..]);
         let b2 = Braid::from_sigmas(&[2, 6, 5, 4, 3, 5], 7);
         assert_eq!(b.gens, b2.gens);
@@ -317,12 +357,24 @@ mod tests {
         println!("{:?}, {:?}", b1.finishing_set(), b2.finishing_set());
         println!();
 
-        let b1 = Braid::from_sigmas(&vec![1, 2], 3);
-        let b2 = Braid::from_sigmas(&vec![2, 1, 2], 3);
+        let b1 = Braid::from_sigmas(&[1, 2], 3);
+        let b2 = Braid::from_sigmas(&[2, 1, 2], 3);
         println!("{:?}, {:?}", b1.starting_set(), b2.starting_set());
         println!("{:?}, {:?}", b1.finishing_set(), b2.finishing_set());
     }
 
+    #[test]
+    fn swap_tests() {
+        let mut b = Braid::from_sigmas(&[1, 2], 4);
+        let mut p = b.as_vec();
+        b.swap_(1, 4);
+        p.swap_(1, 4);
+        assert_eq!(b.as_vec(), p);
+        b.swap_(3, 2);
+        p.swap_(3, 2);
+        assert_eq!(b.as_vec(), p);
+    }
+
     #[test]
     fn test_serialization() {
         //TODO expand test cases, improve memory usage
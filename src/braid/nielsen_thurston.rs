@@ -0,0 +1,269 @@
+use crate::braid::{
+    garside::GarsideForm,
+    simple::{
+        invert_permutation,
+        SimpleBraid,
+    },
+    *,
+};
+
+/*
+ * The Nielsen-Thurston type of a braid. A braid is periodic when some power
+ * is a power of delta_n^2, which only has to be checked for the n-th and
+ * (n - 1)-th powers since every periodic braid is conjugate to a power of
+ * sigma_1 ... sigma_(n - 1) or of sigma_1 ... sigma_(n - 1) sigma_1. A braid
+ * is reducible when it preserves a family of disjoint essential curves.
+ * Gonzalez-Meneses and Wiest showed that for braids in their stabilized
+ * sliding circuits such curves can be taken round or almost round, and
+ * almost round curves become round after conjugating by a generator.
+ * Bernadete, Gutierrez and Nitecki showed that if delta_n^p A_1 ... A_r
+ * sends a round curve to a round curve then so does every prefix
+ * delta_n^p A_1 ... A_i, so round curves can be followed factor by factor
+ */
+
+/**
+ * The three Nielsen-Thurston types
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NielsenThurstonType {
+    // Some power is a power of delta_n^2
+    Periodic,
+    // Not periodic, and some family of essential curves is preserved
+    Reducible,
+    // Neither of the above
+    PseudoAnosov,
+}
+
+/**
+ * A round curve: the boundary of a disk around the punctures
+ * first ..= last in a straight line
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RoundCurve {
+    pub first: usize,
+    pub last: usize,
+}
+
+impl RoundCurve {
+    pub fn new(first: usize, last: usize) -> Self {
+        debug_assert!(first <= last);
+        Self { first, last }
+    }
+
+    /**
+     * The number of punctures inside
+     */
+    pub fn punctures(&self) -> usize {
+        self.last - self.first + 1
+    }
+
+    /**
+     * Are the two curves disjoint, i.e. are the disks nested or disjoint?
+     */
+    pub fn is_disjoint_from(&self, other: &Self) -> bool {
+        let nested = (self.first <= other.first && other.last <= self.last)
+            || (other.first <= self.first && self.last <= other.last);
+        nested || self.last < other.first || other.last < self.first
+    }
+
+    /**
+     * The image of self under a simple braid, if it is round: the strands
+     * starting inside have to end next to each other. Strands outside then
+     * cross either all of them or none of them
+     */
+    pub fn image_under(&self, s: &SimpleBraid) -> Option<Self> {
+        let positions = invert_permutation(s.permutation());
+        let ends = || positions[self.first - 1..self.last].iter();
+        let first = *ends().min().unwrap();
+        let last = *ends().max().unwrap();
+        (last - first + 1 == self.punctures()).then_some(Self { first, last })
+    }
+
+    /**
+     * The image of self under delta_n^k, which reflects it when k is odd
+     */
+    fn image_under_delta(&self, k: isize, n: usize) -> Self {
+        if k % 2 == 0 {
+            *self
+        } else {
+            Self {
+                first: n + 1 - self.last,
+                last: n + 1 - self.first,
+            }
+        }
+    }
+}
+
+impl GarsideForm {
+    /**
+     * Is some power of self a power of delta_n^2?
+     */
+    pub fn is_periodic(&self) -> bool {
+        let is_central = |x: &Self| x.permutations.is_empty() && x.delta_exp % 2 == 0;
+        self.n <= 1 || is_central(&self.pow(self.n)) || is_central(&self.pow(self.n - 1))
+    }
+
+    /**
+     * The image of a round curve under self, if it is round
+     */
+    pub fn round_image(&self, curve: &RoundCurve) -> Option<RoundCurve> {
+        self.simple_factors()
+            .try_fold(curve.image_under_delta(self.delta_exp, self.n), |c, s| {
+                c.image_under(&s)
+            })
+    }
+
+    /**
+     * The essential round curves whose orbit under self consists of
+     * disjoint round curves, which then form a family of reduction curves
+     */
+    pub fn round_reduction_curves(&self) -> Vec<RoundCurve> {
+        let mut res = vec![];
+        for len in 2..self.n {
+            for first in 1..=self.n + 1 - len {
+                let curve = RoundCurve::new(first, first + len - 1);
+                let mut orbit = vec![curve];
+                let mut next = self.round_image(&curve);
+                while let Some(c) = next {
+                    if c == curve || orbit.len() > self.n * self.n {
+                        break;
+                    }
+                    orbit.push(c);
+                    next = self.round_image(&c);
+                }
+                let closed = next == Some(curve);
+                if closed
+                    && orbit
+                        .iter()
+                        .all(|a| orbit.iter().all(|b| a.is_disjoint_from(b)))
+                {
+                    res.push(curve);
+                }
+            }
+        }
+        res
+    }
+
+    /**
+     * Does self, or its conjugate by a generator or its inverse, preserve a
+     * family of round curves?
+     */
+    fn has_almost_round_reduction_curves(&self) -> bool {
+        if !self.round_reduction_curves().is_empty() {
+            return true;
+        }
+        (1..self.n).any(|i| {
+            let s = Self::from_simple(SimpleBraid::sigma(i, self.n));
+            [s.clone(), s.inverse()]
+                .iter()
+                .any(|c| !self.conjugate(c).round_reduction_curves().is_empty())
+        })
+    }
+
+    /**
+     * The Nielsen-Thurston type of self. Reducible braids are looked for
+     * among the sliding circuits of the first n powers of self, by their
+     * round and almost round reduction curves
+     */
+    pub fn nielsen_thurston_type(&self) -> NielsenThurstonType {
+        if self.is_periodic() {
+            return NielsenThurstonType::Periodic;
+        }
+        for k in 1..=self.n {
+            let power = self.pow(k);
+            if power
+                .sliding_circuits()
+                .iter()
+                .any(|y| y.has_almost_round_reduction_curves())
+            {
+                return NielsenThurstonType::Reducible;
+            }
+        }
+        NielsenThurstonType::PseudoAnosov
+    }
+}
+
+impl Braid {
+    /**
+     * Is some power of self a power of delta_n^2?
+     */
+    pub fn is_periodic(&self) -> bool {
+        self.as_garside_form().is_periodic()
+    }
+
+    /**
+     * The Nielsen-Thurston type of self
+     */
+    pub fn nielsen_thurston_type(&self) -> NielsenThurstonType {
+        self.as_garside_form().nielsen_thurston_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_curve_tests() {
+        // sigma_2 sigma_1 sigma_3 sigma_2 swaps the pairs 1, 2 and 3, 4
+        let x = Braid::from_sigmas(&[2, 1, 3, 2, 1], 4).as_garside_form();
+        assert_eq!(
+            x.round_image(&RoundCurve::new(1, 2)),
+            Some(RoundCurve::new(3, 4))
+        );
+        assert_eq!(x.round_image(&RoundCurve::new(2, 3)), None);
+        assert_eq!(
+            x.round_reduction_curves(),
+            vec![RoundCurve::new(1, 2), RoundCurve::new(3, 4)]
+        );
+
+        // The orbit of 1, 2 under sigma_2 sigma_1 also contains 2, 3
+        let x = Braid::from_sigmas(&[2, 1], 4).as_garside_form();
+        assert!(!x.round_reduction_curves().contains(&RoundCurve::new(1, 2)));
+        assert!(!RoundCurve::new(1, 2).is_disjoint_from(&RoundCurve::new(2, 3)));
+        assert!(RoundCurve::new(1, 3).is_disjoint_from(&RoundCurve::new(2, 3)));
+    }
+
+    #[test]
+    fn nielsen_thurston_tests() {
+        use NielsenThurstonType::*;
+
+        assert_eq!(Braid::make_half_twist(5).nielsen_thurston_type(), Periodic);
+        assert_eq!(
+            Braid::from_sigmas(&[1, 2, 3, 4], 5).nielsen_thurston_type(),
+            Periodic
+        );
+        assert_eq!(
+            Braid::from_sigmas(&[1, 2, 3, 1], 4).nielsen_thurston_type(),
+            Periodic
+        );
+        assert_eq!(
+            Braid::from_sigmas(&[1, -2], 3).nielsen_thurston_type(),
+            PseudoAnosov
+        );
+        assert_eq!(
+            Braid::from_sigmas(&[1, 3, -2, -4], 5).nielsen_thurston_type(),
+            PseudoAnosov
+        );
+        assert_eq!(
+            Braid::from_sigmas(&[1], 3).nielsen_thurston_type(),
+            Reducible
+        );
+        assert_eq!(
+            Braid::from_sigmas(&[1, 1, -3], 4).nielsen_thurston_type(),
+            Reducible
+        );
+
+        // Conjugation hides the round curves but not the type
+        let a = Braid::from_sigmas(&[2, -3, 1, 2, 2, -1, 3], 5);
+        for (x, t) in [
+            (Braid::from_sigmas(&[1, -2, 4, 4], 5), Reducible),
+            (Braid::from_sigmas(&[2, 1, 3, 2, 1, -3], 5), Reducible),
+            (Braid::from_sigmas(&[1, 2, 1, 1, -4], 5), Reducible),
+            (Braid::from_sigmas(&[1, -2, 3, -4], 5), PseudoAnosov),
+        ] {
+            let y = a.clone() * x * a.inverse();
+            assert_eq!(y.nielsen_thurston_type(), t);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use crate::braid::{
+    canonical::CanonicalBraid,
+    *,
+};
+
+/*
+ * The Dehornoy order. A word is sigma-positive when the generator of
+ * smallest index in it only appears with positive exponent, and a braid is
+ * sigma-positive when some word for it is. Every non-trivial braid is either
+ * sigma-positive or sigma-negative but not both, and a word without handles
+ * is already one of the two, so handle reduction decides it, as do
+ * Dynnikov coordinates (see dynnikov.rs). Then x < y
+ * exactly when x^-1 y is sigma-positive, which is invariant under
+ * multiplication on the left
+ */
+
+impl Braid {
+    /**
+     * Greater if self is sigma-positive, Less if it is sigma-negative and
+     * Equal if it is trivial. Read off Dynnikov coordinates, or off the
+     * handle reduced word if they overflow
+     */
+    pub fn sigma_sign(&self) -> Ordering {
+        self.dynnikov_sigma_sign()
+            .unwrap_or_else(|_| self.handle_sigma_sign())
+    }
+
+    /**
+     * sigma_sign through handle reduction
+     */
+    pub fn handle_sigma_sign(&self) -> Ordering {
+        let reduced = self.handle_reduced();
+        let main = reduced.gens.iter().map(|&g| isize::from(g)).min_by_key(|g| g.abs());
+        match main {
+            Some(g) => g.cmp(&0),
+            None => Ordering::Equal,
+        }
+    }
+
+    pub fn is_sigma_positive(&self) -> bool {
+        self.sigma_sign() == Ordering::Greater
+    }
+
+    pub fn is_sigma_negative(&self) -> bool {
+        self.sigma_sign() == Ordering::Less
+    }
+
+    /**
+     * Compare self and other in the Dehornoy order, after the number of
+     * strands: self < other exactly when self^-1 other is sigma-positive
+     */
+    pub fn dehornoy_cmp(&self, other: &Self) -> Ordering {
+        self.n
+            .cmp(&other.n)
+            .then_with(|| (self.inverse() * other.clone()).sigma_sign().reverse())
+    }
+}
+
+impl CanonicalBraid {
+    /**
+     * Compare in the Dehornoy order rather than the order on normal forms
+     * used by Ord, which is cheaper but has no meaning in the group
+     */
+    pub fn dehornoy_cmp(&self, other: &Self) -> Ordering {
+        self.to_braid().dehornoy_cmp(&other.to_braid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sigma_positive_tests() {
+        assert!(Braid::from_sigmas(&[1], 3).is_sigma_positive());
+        assert!(Braid::from_sigmas(&[2, -1], 3).is_sigma_negative());
+        assert!(Braid::from_sigmas(&[1, -2, -2, -2, -2, -2], 3).is_sigma_positive());
+        assert!(Braid::make_half_twist(5).is_sigma_positive());
+        // sigma_1 sigma_2 sigma_1^-1 = sigma_2^-1 sigma_1 sigma_2
+        assert!(Braid::from_sigmas(&[1, 2, -1], 3).is_sigma_positive());
+        assert_eq!(Braid::from_sigmas(&[1, 2, 1, -2, -1, -2], 3).sigma_sign(), Ordering::Equal);
+
+        for _ in 0..10 {
+            let x = Braid::random_positive(5, 2, 4, 0.1) * Braid::random_positive(5, 2, 4, 0.1).inverse();
+            if x.is_equivalent(&Braid::from_sigmas(&[], 5)) {
+                continue;
+            }
+            assert_ne!(x.is_sigma_positive(), x.is_sigma_negative());
+            assert_eq!(x.is_sigma_positive(), x.inverse().is_sigma_negative());
+        }
+    }
+
+    #[test]
+    fn dehornoy_cmp_tests() {
+        let e = Braid::from_sigmas(&[], 4);
+        let s1 = Braid::from_sigmas(&[1], 4);
+        let s2 = Braid::from_sigmas(&[2], 4);
+        assert_eq!(e.dehornoy_cmp(&s1), Ordering::Less);
+        assert_eq!(s2.dehornoy_cmp(&s1), Ordering::Less);
+        assert_eq!(Braid::from_sigmas(&[2, 1, 2], 4).dehornoy_cmp(&Braid::from_sigmas(&[1, 2, 1], 4)), Ordering::Equal);
+        assert_eq!(
+            CanonicalBraid::new(&s1).dehornoy_cmp(&CanonicalBraid::new(&s2)),
+            Ordering::Greater
+        );
+
+        let braids: Vec<Braid> = (0..6)
+            .map(|_| Braid::random_positive(4, 2, 3, 0.1) * Braid::random_positive(4, 2, 3, 0.1).inverse())
+            .collect();
+        let c = Braid::random_positive(4, 2, 3, 0.1) * Braid::from_sigmas(&[-2, 1], 4);
+        for x in &braids {
+            for y in &braids {
+                let xy = x.dehornoy_cmp(y);
+                assert_eq!(xy, y.dehornoy_cmp(x).reverse());
+                // Left invariance
+                assert_eq!((c.clone() * x.clone()).dehornoy_cmp(&(c.clone() * y.clone())), xy);
+                for z in &braids {
+                    if xy == Ordering::Less && y.dehornoy_cmp(z) == Ordering::Less {
+                        assert_eq!(x.dehornoy_cmp(z), Ordering::Less);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -90,10 +90,10 @@ impl Braid {
     pub fn swap_mutation(&mut self) {
         for idx in 0..self.gens.len() - 1 {
             match (self.gens[idx], self.gens[idx + 1]) {
-                (BrGen::Sigma(a), BrGen::Sigma(b)) | (BrGen::SigmaInv(a), BrGen::SigmaInv(b)) => {
-                    if a.abs_diff(b) > 1 {
-                        self.gens.swap(idx, idx + 1);
-                    }
+                (BrGen::Sigma(a), BrGen::Sigma(b)) | (BrGen::SigmaInv(a), BrGen::SigmaInv(b))
+                    if a.abs_diff(b) > 1 =>
+                {
+                    self.gens.swap(idx, idx + 1);
                 }
                 _ => {}
             }
@@ -107,19 +107,15 @@ impl Braid {
         let kern3 = self.gens[idx + 2];
 
         match (kern1, kern2, kern3) {
-            (BrGen::Sigma(a), BrGen::Sigma(b), BrGen::Sigma(c)) => {
-                if a == c && b == a + 1 {
-                    self.gens[idx] = BrGen::Sigma(a + 1);
-                    self.gens[idx + 1] = BrGen::Sigma(a);
-                    self.gens[idx + 2] = BrGen::Sigma(a + 1);
-                }
+            (BrGen::Sigma(a), BrGen::Sigma(b), BrGen::Sigma(c)) if a == c && b == a + 1 => {
+                self.gens[idx] = BrGen::Sigma(a + 1);
+                self.gens[idx + 1] = BrGen::Sigma(a);
+                self.gens[idx + 2] = BrGen::Sigma(a + 1);
             }
-            (BrGen::SigmaInv(a), BrGen::SigmaInv(b), BrGen::SigmaInv(c)) => {
-                if a == c && b == a + 1 {
-                    self.gens[idx] = BrGen::SigmaInv(a + 1);
-                    self.gens[idx + 1] = BrGen::SigmaInv(a);
-                    self.gens[idx + 2] = BrGen::SigmaInv(a + 1);
-                }
+            (BrGen::SigmaInv(a), BrGen::SigmaInv(b), BrGen::SigmaInv(c)) if a == c && b == a + 1 => {
+                self.gens[idx] = BrGen::SigmaInv(a + 1);
+                self.gens[idx + 1] = BrGen::SigmaInv(a);
+                self.gens[idx + 2] = BrGen::SigmaInv(a + 1);
             }
             _ => {}
         }
@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+
+use crate::braid::{
+    garside::GarsideForm,
+    simple::SimpleBraid,
+    summit::{
+        ConjugacyBackend,
+        SummitGraph,
+    },
+    *,
+};
+
+/*
+ * The root problem (Sang Jae Lee and Eon-Kyung Lee). If a^k = x then a has
+ * a conjugate a' in its stable super summit set, whose powers all lie in
+ * the super summit sets of their conjugacy classes. So a'^k is an element x'
+ * of the super summit set of x, and inf(a') and sup(a') are inf_s(x) / k
+ * rounded down and sup_s(x) / k rounded up. Writing a' = delta_n^u P with P
+ * positive, delta_n^-ku x' = tau^((k - 1)u)(P) ... tau^u(P) P, so P is a
+ * prefix of a known positive braid and can be searched for factor by factor.
+ * The search is exponential in the canonical length of the root
+ */
+
+/**
+ * The simple braids which are prefixes of s, including the identity and s
+ */
+fn simple_prefixes(s: &SimpleBraid) -> Vec<SimpleBraid> {
+    let n = s.n();
+    let mut seen = HashSet::new();
+    let mut res = vec![SimpleBraid::identity(n)];
+    let mut i = 0;
+    while i < res.len() {
+        let t = res[i].clone();
+        for j in 1..n {
+            if let Some(next) = t.try_mul(&SimpleBraid::sigma(j, n))
+                && next.is_left_divisor_of(s)
+                && seen.insert(next.clone())
+            {
+                res.push(next);
+            }
+        }
+        i += 1;
+    }
+    res
+}
+
+impl GarsideForm {
+    /**
+     * self^k
+     */
+    pub fn pow(&self, k: usize) -> Self {
+        (0..k).fold(Self::identity(self.n), |acc, _| acc * self.clone())
+    }
+
+    /**
+     * tau(self) = delta_n^-1 self delta_n
+     */
+    fn tau(&self) -> Self {
+        let mut delta = Self::identity(self.n);
+        delta.delta_exp = 1;
+        self.conjugate(&delta)
+    }
+
+    /**
+     * A root a = delta_n^u P of self with P positive of canonical length l,
+     * where factors is the start of the normal form of P and rest is the
+     * part of tau^((k - 1)u)(delta_n^-ku self) after it
+     */
+    fn extend_root(
+        &self,
+        k: usize,
+        u: isize,
+        l: usize,
+        factors: &mut Vec<SimpleBraid>,
+        rest: Self,
+    ) -> Option<Self> {
+        if factors.len() == l {
+            let mut root = Self::identity(self.n);
+            root.delta_exp = u;
+            for s in factors.iter() {
+                root = root * Self::from_simple(s.clone());
+            }
+            return (root.pow(k) == *self).then_some(root);
+        }
+
+        let delta = SimpleBraid::delta(self.n);
+        for t in simple_prefixes(&rest.head()) {
+            if t.is_identity() || t == delta {
+                continue;
+            }
+            if factors
+                .last()
+                .is_some_and(|prev| !prev.finishing_set().is_superset(&t.starting_set()))
+            {
+                continue;
+            }
+            let next = Self::from_simple(t.clone()).inverse() * rest.clone();
+            // The remaining factors of P are a prefix of next
+            if next.sup() < (l - factors.len() - 1) as isize {
+                continue;
+            }
+            factors.push(t);
+            let root = self.extend_root(k, u, l, factors, next);
+            factors.pop();
+            if root.is_some() {
+                return root;
+            }
+        }
+        None
+    }
+
+    /**
+     * A k-th root of self whose infimum and supremum are u and u + l
+     */
+    fn root_with_bounds(&self, k: usize, u: isize, l: usize) -> Option<Self> {
+        let mut w = self.clone();
+        w.delta_exp -= k as isize * u;
+        if w.delta_exp < 0 {
+            return None;
+        }
+        // tau^((k - 1)u)(P) is a prefix of w
+        let v = if ((k as isize - 1) * u) % 2 == 0 {
+            w
+        } else {
+            w.tau()
+        };
+        self.extend_root(k, u, l, &mut vec![], v)
+    }
+
+    /**
+     * A braid a with a^k = self, if there is one
+     */
+    pub fn root(&self, k: usize) -> Option<Self> {
+        assert!(k > 0, "there are no 0-th roots");
+        if k == 1 {
+            return Some(self.clone());
+        }
+
+        let (y, c) = self.super_summit_element();
+        let u = y.inf().div_euclid(k as isize);
+        let sup = -(-y.sup()).div_euclid(k as isize);
+        let l = (sup - u) as usize;
+
+        let mut found = None;
+        let graph =
+            SummitGraph::explore(y, Self::summit_arrows(ConjugacyBackend::SuperSummit), |z| {
+                found = z.root_with_bounds(k, u, l).map(|a| (z.clone(), a));
+                found.is_some()
+            });
+        // z = d^-1 y d and y = c^-1 self c, so c d a d^-1 c^-1 is a root of self
+        found.map(|(z, a)| {
+            let d = c * graph.conjugator_from_root(&z).unwrap().clone();
+            d.clone() * a * d.inverse()
+        })
+    }
+}
+
+impl Braid {
+    /**
+     * A k-th root of self, if there is one. The k-th roots of a braid are
+     * all conjugate to each other (Gonzalez-Meneses), so this is all of them
+     * up to conjugacy
+     */
+    pub fn roots(&self, k: usize) -> Option<Self> {
+        self.as_garside_form().root(k).map(|a| a.to_braid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roots_tests() {
+        let s1 = Braid::from_sigmas(&[1], 3);
+        let root = Braid::from_sigmas(&[1, 1], 3).roots(2).unwrap();
+        assert!(root.is_equivalent(&s1));
+        assert!(s1.roots(2).is_none());
+        assert!(s1.roots(1).unwrap().is_equivalent(&s1));
+
+        // delta_n^2 is the square of delta_n, the n-th power of
+        // sigma_1 ... sigma_(n - 1) and the (n - 1)-th power of
+        // sigma_1 ... sigma_(n - 1) sigma_1
+        let full_twist = Braid::make_half_twist_power(4, 2);
+        for k in [2, 3, 4] {
+            let root = full_twist.roots(k).unwrap();
+            assert_eq!(root.as_garside_form().pow(k), full_twist.as_garside_form());
+        }
+        assert!(full_twist.roots(5).is_none());
+
+        for (word, n, k) in [
+            (&[1, 2, -3][..], 4, 2),
+            (&[2, 1, 1, -3, 2][..], 4, 3),
+            (&[1, -2][..], 3, 2),
+            (&[1, 2, 3, -4, 2][..], 5, 2),
+        ] {
+            let a = Braid::from_sigmas(word, n).as_garside_form();
+            let x = a.pow(k);
+            let root = x.root(k).unwrap();
+            assert_eq!(root.pow(k), x);
+        }
+        assert!(Braid::from_sigmas(&[1, 2, -3], 4).roots(2).is_none());
+    }
+}
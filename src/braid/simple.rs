@@ -0,0 +1,587 @@
+use indexmap::set::IndexSet;
+
+use crate::{
+    braid::*,
+    permutation::*,
+};
+
+/**
+ * The permutation of the reversed permutation braid, which is the inverse permutation
+ * O(n)
+ */
+pub(crate) fn invert_permutation(p: &[usize]) -> VecPermutation {
+    let mut res = vec![0; p.len()];
+    for (i, strand) in p.iter().enumerate() {
+        res[*strand - 1] = i + 1;
+    }
+    res
+}
+
+/**
+ * Conjugate a permutation braid by delta_n, which replaces sigma_i with sigma_{n - i}
+ * O(n)
+ */
+pub(crate) fn tau_permutation(p: &[usize]) -> VecPermutation {
+    let n = p.len();
+    p.iter().rev().map(|strand| n + 1 - strand).collect()
+}
+
+/**
+ * The permutation of the product braid of permutation braids a and b
+ * O(n)
+ */
+pub(crate) fn compose_permutations(a: &[usize], b: &[usize]) -> VecPermutation {
+    b.iter().map(|strand| a[*strand - 1]).collect()
+}
+
+/**
+ * The permutation of the generator sigma_i in B_n
+ */
+pub(crate) fn sigma_permutation(i: usize, n: usize) -> VecPermutation {
+    let mut p = VecPermutation::id(n);
+    p.swap_(i, i + 1);
+    p
+}
+
+/**
+ * The permutation of a^-1 delta_n, the simple element completing a to delta_n
+ * O(n)
+ */
+pub(crate) fn delta_complement(a: &[usize]) -> VecPermutation {
+    let mut res = invert_permutation(a);
+    res.reverse();
+    res
+}
+
+/**
+ * The permutation of the greatest common prefix of the permutation braids
+ * a and b. This is Thurston's merge sort: the strands are sorted into the
+ * order they end in the meet, and while merging two sorted runs a strand
+ * of the right run may only overtake the rest of the left run if it ends
+ * to the left of all of it in both a and b
+ * NOTE: See Section 9.5 of Epstein et al., Word Processing in Groups
+ * O(n log n)
+ */
+pub(crate) fn meet_permutations(a: &[usize], b: &[usize]) -> VecPermutation {
+    let n = a.len();
+    debug_assert_eq!(n, b.len());
+    let a_positions = invert_permutation(a);
+    let b_positions = invert_permutation(b);
+    let mut strands: VecPermutation = (1..=n).collect();
+    let mut merged = strands.clone();
+    // Smallest positions in a and b over each suffix of the left run
+    let mut a_min = vec![0; n + 1];
+    let mut b_min = vec![0; n + 1];
+    let mut width = 1;
+    while width < n {
+        for start in (0..n).step_by(2 * width) {
+            let mid = (start + width).min(n);
+            let end = (start + 2 * width).min(n);
+            a_min[mid] = usize::MAX;
+            b_min[mid] = usize::MAX;
+            for k in (start..mid).rev() {
+                a_min[k] = a_min[k + 1].min(a_positions[strands[k] - 1]);
+                b_min[k] = b_min[k + 1].min(b_positions[strands[k] - 1]);
+            }
+            let (mut i, mut j) = (start, mid);
+            for slot in &mut merged[start..end] {
+                let overtakes = j < end
+                    && (i == mid
+                        || a_positions[strands[j] - 1] < a_min[i]
+                            && b_positions[strands[j] - 1] < b_min[i]);
+                if overtakes {
+                    *slot = strands[j];
+                    j += 1;
+                } else {
+                    *slot = strands[i];
+                    i += 1;
+                }
+            }
+        }
+        std::mem::swap(&mut strands, &mut merged);
+        width *= 2;
+    }
+    strands
+}
+
+/**
+ * Make the pair of permutation braids a b left weighted without changing
+ * their product, i.e. until S(b) is in F(a). If it is not already, the
+ * part to move is c = (a^-1 delta_n) meet b, and the pair becomes
+ * (a c, c^-1 b)
+ * Returns whether anything moved
+ * O(n) if the pair is already left weighted, O(n log n) otherwise
+ */
+pub(crate) fn left_weight_pair(a: &mut VecPermutation, b: &mut VecPermutation) -> bool {
+    let n = a.len();
+    // Where each strand ends up in b
+    let b_positions = invert_permutation(b);
+    // Strands starting at j and j + 1 cross in b exactly when sigma_j is in S(b),
+    // and strands ending at j and j + 1 cross in a exactly when it is in F(a)
+    let weighted = (1..n).all(|j| b_positions[j - 1] < b_positions[j] || a[j - 1] > a[j]);
+    if weighted {
+        return false;
+    }
+    let c = meet_permutations(&delta_complement(a), b);
+    *a = compose_permutations(a, &c);
+    *b = compose_permutations(&invert_permutation(&c), b);
+    true
+}
+
+/**
+ * A positive permutation braid (simple element of B_n), a braid in which
+ * every pair of strands crosses at most once. It is stored as the permutation
+ * it induces, in the same convention as Braid::as_vec: the entry at
+ * position i - 1 is the strand that ends at position i
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SimpleBraid {
+    perm: VecPermutation,
+}
+
+impl SimpleBraid {
+    /**
+     * The simple braid inducing perm. Every permutation has exactly one
+     */
+    pub fn from_permutation(perm: VecPermutation) -> Self {
+        debug_assert!(
+            {
+                let mut sorted = perm.clone();
+                sorted.sort_unstable();
+                sorted.into_iter().eq(1..=perm.len())
+            },
+            "A non-permutation was passed to from_permutation"
+        );
+        Self { perm }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: VecPermutation::id(n),
+        }
+    }
+
+    /**
+     * The half twist delta_n, the largest simple braid
+     */
+    pub fn delta(n: usize) -> Self {
+        Self {
+            perm: (1..=n).rev().collect(),
+        }
+    }
+
+    pub fn sigma(i: usize, n: usize) -> Self {
+        Self {
+            perm: sigma_permutation(i, n),
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.perm.len()
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.perm
+    }
+
+    pub fn into_vec(self) -> VecPermutation {
+        self.perm
+    }
+
+    /**
+     * A positive word for this braid
+     * O(n^2)
+     */
+    pub fn to_braid(&self) -> Braid {
+        Braid::from_slice(&self.perm[..])
+    }
+
+    /**
+     * The number of crossings, which is the length of any positive word for it
+     * O(n^2)
+     */
+    pub fn crossings(&self) -> usize {
+        let n = self.n();
+        (0..n)
+            .map(|i| (i + 1..n).filter(|j| self.perm[i] > self.perm[*j]).count())
+            .sum()
+    }
+
+    /**
+     * delta_n self delta_n^-1, which replaces each sigma_i with sigma_{n - i}
+     * O(n)
+     */
+    pub fn tau(&self) -> Self {
+        Self {
+            perm: tau_permutation(&self.perm),
+        }
+    }
+
+    /**
+     * The simple braid with the generators in the opposite order
+     * O(n)
+     */
+    pub fn reversed(&self) -> Self {
+        Self {
+            perm: invert_permutation(&self.perm),
+        }
+    }
+
+    /**
+     * The set of i such that sigma_i is a left divisor: the strands
+     * starting at i and i + 1 cross
+     * NOTE: See Proposition 2.4 (2) of Elrifai Morton
+     * O(n)
+     */
+    pub fn starting_set(&self) -> IndexSet<usize> {
+        let positions = invert_permutation(&self.perm);
+        (1..self.n())
+            .filter(|i| positions[*i - 1] > positions[*i])
+            .collect()
+    }
+
+    /**
+     * The set of i such that sigma_i is a right divisor: the strands
+     * ending at i and i + 1 cross
+     * O(n)
+     */
+    pub fn finishing_set(&self) -> IndexSet<usize> {
+        (1..self.n())
+            .filter(|i| self.perm[*i - 1] > self.perm[*i])
+            .collect()
+    }
+
+    /**
+     * The simple braid c with self c = delta_n
+     * O(n)
+     */
+    pub fn right_complement(&self) -> Self {
+        Self {
+            perm: delta_complement(&self.perm),
+        }
+    }
+
+    /**
+     * The simple braid c with c self = delta_n
+     * O(n)
+     */
+    pub fn left_complement(&self) -> Self {
+        // delta_n a^-1 = tau(a^-1 delta_n)
+        self.right_complement().tau()
+    }
+
+    /**
+     * The product self other, if it is still simple. This happens exactly
+     * when no pair of strands crosses in both, so crossings add up
+     * O(n^2)
+     */
+    pub fn try_mul(&self, other: &Self) -> Option<Self> {
+        debug_assert_eq!(self.n(), other.n());
+        let product = Self {
+            perm: compose_permutations(&self.perm, &other.perm),
+        };
+        (product.crossings() == self.crossings() + other.crossings()).then_some(product)
+    }
+
+    /**
+     * Is self a prefix of other, i.e. other = self c for a positive c?
+     * O(n log n)
+     */
+    pub fn is_left_divisor_of(&self, other: &Self) -> bool {
+        self.left_meet(other) == *self
+    }
+
+    /**
+     * Is self a suffix of other, i.e. other = c self for a positive c?
+     * O(n log n)
+     */
+    pub fn is_right_divisor_of(&self, other: &Self) -> bool {
+        self.right_meet(other) == *self
+    }
+
+    /**
+     * The greatest common prefix of self and other in the weak order,
+     * see meet_permutations
+     * O(n log n)
+     */
+    pub fn left_meet(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.n(), other.n());
+        Self {
+            perm: meet_permutations(&self.perm, &other.perm),
+        }
+    }
+
+    /**
+     * The greatest common suffix of self and other in the weak order
+     * O(n log n)
+     */
+    pub fn right_meet(&self, other: &Self) -> Self {
+        self.reversed().left_meet(&other.reversed()).reversed()
+    }
+
+    /**
+     * The smallest simple braid with both self and other as prefixes.
+     * Complements reverse divisibility, so this is the left complement
+     * of the greatest common suffix of the right complements
+     * O(n log n)
+     */
+    pub fn left_join(&self, other: &Self) -> Self {
+        self.right_complement()
+            .right_meet(&other.right_complement())
+            .left_complement()
+    }
+
+    /**
+     * The smallest simple braid with both self and other as suffixes
+     * O(n log n)
+     */
+    pub fn right_join(&self, other: &Self) -> Self {
+        self.left_complement()
+            .left_meet(&other.left_complement())
+            .right_complement()
+    }
+
+    /**
+     * self \ other = self^-1 (self v other), the smallest simple braid c
+     * such that other is a prefix of self c
+     * O(n log n)
+     */
+    pub fn residual(&self, other: &Self) -> Self {
+        let join = self.left_join(other);
+        Self {
+            perm: compose_permutations(&invert_permutation(&self.perm), &join.perm),
+        }
+    }
+
+    /**
+     * Make self other left weighted without changing the product, see
+     * left_weight_pair. Returns whether anything moved
+     * O(n) if already left weighted, O(n log n) otherwise
+     */
+    pub fn left_weight(&mut self, other: &mut Self) -> bool {
+        left_weight_pair(&mut self.perm, &mut other.perm)
+    }
+}
+
+/**
+ * The residual a \ b of two positive braids given as products of simple
+ * braids: the smallest positive c such that b is a prefix of a c, again as a
+ * product of simple braids. Uses a \ (b_1 b') = (a \ b_1) ((b_1 \ a) \ b')
+ * for simple a, and (a_1 a') \ b = a' \ (a_1 \ b)
+ * O(|a| * |b| * n^2)
+ */
+pub(crate) fn word_residual(a: &[SimpleBraid], b: &[SimpleBraid]) -> Vec<SimpleBraid> {
+    let mut res = b.to_vec();
+    for a in a {
+        let mut a = a.clone();
+        for b in res.iter_mut() {
+            let next = b.residual(&a);
+            *b = a.residual(b);
+            a = next;
+        }
+    }
+    res
+}
+
+impl Permutation for SimpleBraid {
+    fn id(n: usize) -> Self {
+        Self::identity(n)
+    }
+
+    fn size(&self) -> usize {
+        self.n()
+    }
+
+    fn swap_(&mut self, a: usize, b: usize) {
+        self.perm.swap_(a, b)
+    }
+
+    fn position(&self, x: usize) -> usize {
+        self.perm.position(x)
+    }
+
+    fn follow_ending(&self, x: usize) -> usize {
+        self.perm[x - 1]
+    }
+
+    fn from_slice(v: &[usize]) -> Self {
+        Self::from_permutation(v.to_vec())
+    }
+
+    fn as_vec(&self) -> Vec<usize> {
+        self.perm.clone()
+    }
+}
+
+impl Braid {
+    /**
+     * The simple braid this word represents, if it is a positive word
+     * in which no two strands cross twice
+     * O(L + n)
+     */
+    pub fn as_simple(&self) -> Option<SimpleBraid> {
+        let mut perm = VecPermutation::id(self.n);
+        for g in &self.gens {
+            let BrGen::Sigma(i) = g else {
+                return None;
+            };
+            // Strands at i and i + 1 crossing again means it is not simple
+            if perm[*i - 1] > perm[*i] {
+                return None;
+            }
+            perm.swap(*i - 1, *i);
+        }
+        Some(SimpleBraid { perm })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::braid::garside::GarsideForm,
+    };
+
+    #[test]
+    fn simple_sets_tests() {
+        let b = Braid::from_sigmas(&[2, 1, 3, 2, 1], 4);
+        let s = b.as_simple().unwrap();
+        assert_eq!(s.starting_set(), b.starting_set());
+        assert_eq!(s.finishing_set(), b.finishing_set());
+        assert_eq!(s.crossings(), 5);
+        assert_eq!(s.to_braid().as_vec(), b.as_vec());
+
+        assert!(Braid::from_sigmas(&[1, 2, 1, 2], 3).as_simple().is_none());
+        assert!(Braid::from_sigmas(&[1, -2], 3).as_simple().is_none());
+
+        let delta = SimpleBraid::delta(5);
+        assert_eq!(delta.starting_set(), (1..5).collect::<IndexSet<_>>());
+        assert_eq!(delta.finishing_set(), (1..5).collect::<IndexSet<_>>());
+        assert_eq!(delta.tau(), delta);
+    }
+
+    #[test]
+    fn simple_complement_tests() {
+        let s = Braid::from_sigmas(&[1, 2, 3, 1], 4).as_simple().unwrap();
+        let delta = SimpleBraid::delta(4);
+        assert_eq!(s.try_mul(&s.right_complement()), Some(delta.clone()));
+        assert_eq!(s.left_complement().try_mul(&s), Some(delta.clone()));
+        assert_eq!(s.try_mul(&s), None);
+        assert_eq!(
+            SimpleBraid::sigma(1, 4).try_mul(&SimpleBraid::sigma(2, 4)),
+            Braid::from_sigmas(&[1, 2], 4).as_simple()
+        );
+    }
+
+    #[test]
+    fn simple_lattice_tests() {
+        let n = 4;
+        let s1 = SimpleBraid::sigma(1, n);
+        let s2 = SimpleBraid::sigma(2, n);
+        let s3 = SimpleBraid::sigma(3, n);
+        let e = SimpleBraid::identity(n);
+
+        assert_eq!(s1.left_meet(&s2), e);
+        assert_eq!(s1.left_join(&s2), Braid::from_sigmas(&[1, 2, 1], n).as_simple().unwrap());
+        assert_eq!(s1.left_join(&s3), Braid::from_sigmas(&[1, 3], n).as_simple().unwrap());
+        assert_eq!(s1.right_join(&s2), s1.left_join(&s2));
+
+        let a = Braid::from_sigmas(&[1, 2, 3], n).as_simple().unwrap();
+        let b = Braid::from_sigmas(&[1, 3, 2], n).as_simple().unwrap();
+        assert_eq!(a.left_meet(&b), s1);
+        assert_eq!(a.right_meet(&b), e);
+        assert!(s1.is_left_divisor_of(&a));
+        assert!(!s2.is_left_divisor_of(&a));
+        assert!(s3.is_right_divisor_of(&a));
+
+        // Check meets and joins against the definitions on every pair in B_4
+        let all: Vec<SimpleBraid> = all_permutations(n)
+            .into_iter()
+            .map(SimpleBraid::from_permutation)
+            .collect();
+        for x in &all {
+            for y in &all {
+                // x is a prefix of y exactly when every pair of strands crossing in x crosses in y
+                let crossed = |s: &SimpleBraid| -> Vec<(usize, usize)> {
+                    let positions = invert_permutation(s.permutation());
+                    (1..=n)
+                        .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+                        .filter(|(i, j)| positions[*i - 1] > positions[*j - 1])
+                        .collect()
+                };
+                let prefix = crossed(x).iter().all(|p| crossed(y).contains(p));
+                assert_eq!(x.is_left_divisor_of(y), prefix);
+
+                let m = x.left_meet(y);
+                assert!(m.is_left_divisor_of(x) && m.is_left_divisor_of(y));
+                let j = x.left_join(y);
+                assert!(x.is_left_divisor_of(&j) && y.is_left_divisor_of(&j));
+                for z in &all {
+                    if z.is_left_divisor_of(x) && z.is_left_divisor_of(y) {
+                        assert!(z.is_left_divisor_of(&m));
+                    }
+                    if x.is_left_divisor_of(z) && y.is_left_divisor_of(z) {
+                        assert!(j.is_left_divisor_of(z));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn left_weight_tests() {
+        let n = 5;
+        let all = all_permutations(n);
+        for x in &all {
+            for y in &all {
+                let (mut a, mut b) = (x.clone(), y.clone());
+                let moved = left_weight_pair(&mut a, &mut b);
+                // The product and its length are unchanged and S(b) is in F(a)
+                assert_eq!(compose_permutations(&a, &b), compose_permutations(x, y));
+                let (a, b) = (SimpleBraid::from_permutation(a), SimpleBraid::from_permutation(b));
+                let length = |p: &VecPermutation| SimpleBraid::from_slice(p).crossings();
+                assert_eq!(a.crossings() + b.crossings(), length(x) + length(y));
+                assert!(b.starting_set().is_subset(&a.finishing_set()));
+                assert_eq!(moved, a.permutation() != &x[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn residual_tests() {
+        let s1 = SimpleBraid::sigma(1, 3);
+        let s2 = SimpleBraid::sigma(2, 3);
+        // sigma_1 v sigma_2 = sigma_1 sigma_2 sigma_1
+        assert_eq!(s1.residual(&s2), Braid::from_sigmas(&[2, 1], 3).as_simple().unwrap());
+        assert_eq!(s1.residual(&s1), SimpleBraid::identity(3));
+        let x = Braid::from_sigmas(&[1, 2], 3).as_simple().unwrap();
+        assert_eq!(x.residual(&s2), s1);
+
+        // a (a \ b) = a v b for products of simple braids
+        let a = Braid::from_sigmas(&[1, 2, 3, 3, 2, 1, 1], 4).as_garside_form();
+        let b = Braid::from_sigmas(&[3, 2, 2, 1, 3], 4).as_garside_form();
+        let res = word_residual(
+            &a.simple_factors().collect::<Vec<_>>(),
+            &b.simple_factors().collect::<Vec<_>>(),
+        );
+        let product = res
+            .into_iter()
+            .fold(a.clone(), |x, c| x * GarsideForm::from_simple(c));
+        assert_eq!(product, a.left_lcm(&b));
+    }
+
+    fn all_permutations(n: usize) -> Vec<VecPermutation> {
+        if n == 0 {
+            return vec![vec![]];
+        }
+        let mut res = vec![];
+        for p in all_permutations(n - 1) {
+            for i in 0..n {
+                let mut q = p.clone();
+                q.insert(i, n);
+                res.push(q);
+            }
+        }
+        res
+    }
+}
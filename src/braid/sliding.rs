@@ -0,0 +1,312 @@
+use crate::braid::{
+    garside::GarsideForm,
+    simple::{
+        word_residual,
+        SimpleBraid,
+    },
+    summit::{
+        ConjugacyBackend,
+        SummitGraph,
+    },
+    ultra::word_to_simple,
+    *,
+};
+
+/*
+ * Cyclic sliding and sets of sliding circuits (Gebhardt and
+ * Gonzalez-Meneses). Sliding conjugates by the preferred prefix, which is the
+ * part of the first factor that can be moved to the end without leaving the
+ * normal form longer. The elements which come back to themselves under
+ * iterated sliding form the sliding circuits, a subset of the ultra summit
+ * set which is explored in the same way through transport and pullback.
+ * Conjugation is x^c = c^-1 x c as in summit.rs
+ */
+
+impl GarsideForm {
+    /**
+     * The preferred prefix iota(self) ^ partial(phi(self)): the meet of the
+     * cycling conjugator tau^p(A_1) with the right complement of the last
+     * factor A_r, or the identity if there are no factors
+     */
+    pub fn preferred_prefix(&self) -> SimpleBraid {
+        match self.simple_factors().last() {
+            Some(last) => self
+                .cycling_conjugator()
+                .left_meet(&last.right_complement()),
+            None => SimpleBraid::identity(self.n),
+        }
+    }
+
+    /**
+     * Cyclic sliding: the conjugate by the preferred prefix
+     */
+    pub fn cyclic_sliding(&self) -> Self {
+        self.conjugate(&Self::from_simple(self.preferred_prefix()))
+    }
+
+    /**
+     * self, s(self), s^2(self), ... up to (but not including) the first
+     * repetition. For self in its sliding circuits this is the whole circuit
+     */
+    pub fn sliding_orbit(&self) -> Vec<Self> {
+        self.orbit_within(Self::cyclic_sliding, || false).unwrap()
+    }
+
+    /**
+     * Is self in the super summit set of its conjugacy class and
+     * periodic under cyclic sliding?
+     */
+    pub fn is_in_sliding_circuits(&self) -> bool {
+        let (y, _) = self.super_summit_element();
+        if y.delta_exp != self.delta_exp || y.sup() != self.sup() {
+            return false;
+        }
+        self.sliding_orbit().last().unwrap().cyclic_sliding() == *self
+    }
+
+    /**
+     * Conjugate into the sliding circuits: go to the super summit set, then
+     * slide until an element repeats. Returns y and c with y = c^-1 self c
+     */
+    pub fn sliding_circuit_element(&self) -> (Self, Self) {
+        self.sliding_circuit_element_within(|| false).unwrap()
+    }
+
+    /**
+     * sliding_circuit_element, giving up with None as soon as stop returns
+     * true. It is called before every cycling, decycling and sliding
+     */
+    pub(crate) fn sliding_circuit_element_within(
+        &self,
+        mut stop: impl FnMut() -> bool,
+    ) -> Option<(Self, Self)> {
+        let (x, cx) = self.super_summit_element_within(&mut stop)?;
+        let orbit = x.orbit_within(Self::cyclic_sliding, &mut stop)?;
+        // The first repeated element is the sliding of the last one
+        let y = orbit.last().unwrap().cyclic_sliding();
+        let mut c = cx;
+        for z in &orbit {
+            if *z == y {
+                break;
+            }
+            c = c * Self::from_simple(z.preferred_prefix());
+        }
+        Some((y, c))
+    }
+
+    /**
+     * The transport of s under sliding: for self in its super summit set and
+     * self^s in it as well, the simple braid s' with s(self)^s' = s(self^s),
+     * given by s' = p(self)^-1 s p(self^s)
+     */
+    pub fn sliding_transport(&self, s: &SimpleBraid) -> SimpleBraid {
+        let s_form = Self::from_simple(s.clone());
+        let y = self.conjugate(&s_form);
+        let t = Self::from_simple(self.preferred_prefix()).inverse()
+            * s_form
+            * Self::from_simple(y.preferred_prefix());
+        t.to_simple()
+    }
+
+    /**
+     * The pullback of t under sliding: the smallest s conjugating self within
+     * its super summit set whose sliding transport has t as a prefix.
+     * With p = p(self) and self^s = delta_n^p B_1 ... B_r, p t has to be a
+     * prefix of both s tau^p(B_1) and s partial(B_r). The second is
+     * (s delta_n) ^ (self^-1 s delta_n^(p + r)), and p t is a prefix of
+     * self^-1 s delta_n^(p + r) exactly when delta_n^r \ tau^(p + r)(X p t)
+     * is a prefix of s, where self = delta_n^p X
+     * O(r^2 * n^2)
+     */
+    pub fn sliding_pullback(&self, t: &SimpleBraid) -> SimpleBraid {
+        let r = self.permutations.len();
+        if r == 0 {
+            return self.minimal_summit_conjugator(t.clone());
+        }
+        let p = self.preferred_prefix();
+        let tau_pr = |s: SimpleBraid| {
+            if (self.delta_exp + r as isize) % 2 == 0 {
+                s
+            } else {
+                s.tau()
+            }
+        };
+
+        let word: Vec<SimpleBraid> = self
+            .simple_factors()
+            .chain([p.clone(), t.clone()])
+            .map(tau_pr)
+            .collect();
+        let deltas = vec![SimpleBraid::delta(self.n); r];
+        let from_last = word_to_simple(word_residual(&deltas, &word), self.n);
+
+        let bound = self.initial_factor_bound(&p, t).left_join(&from_last);
+        self.minimal_summit_conjugator(bound)
+    }
+
+    /**
+     * For self in its sliding circuits, the smallest simple braid with u as
+     * a prefix which conjugates self to another element of the sliding
+     * circuits. These are exactly the conjugators which are periodic under
+     * sliding transport around the circuit
+     */
+    pub fn minimal_sliding_conjugator(&self, u: SimpleBraid) -> SimpleBraid {
+        let orbit = self.sliding_orbit();
+        self.minimal_periodic_conjugator(
+            u,
+            |s| orbit.iter().fold(s, |s, z| z.sliding_transport(&s)),
+            |s| orbit.iter().rev().fold(s, |s, z| z.sliding_pullback(&s)),
+        )
+    }
+
+    pub(crate) fn sliding_conjugators(&self) -> Vec<SimpleBraid> {
+        self.minimal_conjugators(|u| self.minimal_sliding_conjugator(u))
+    }
+
+    /**
+     * The graph of the sliding circuits of the conjugacy class of self,
+     * with an arrow for every minimal simple conjugator
+     */
+    pub fn sliding_circuits_graph(&self) -> SummitGraph {
+        self.summit_graph(ConjugacyBackend::SlidingCircuits)
+    }
+
+    /**
+     * The set of sliding circuits: the elements of the super summit set
+     * which are periodic under cyclic sliding
+     */
+    pub fn sliding_circuits(&self) -> Vec<Self> {
+        self.sliding_circuits_graph().into_sorted_vertices()
+    }
+}
+
+impl Braid {
+    /**
+     * The set of sliding circuits of the conjugacy class of self
+     */
+    pub fn sliding_circuits(&self) -> Vec<GarsideForm> {
+        self.as_garside_form().sliding_circuits()
+    }
+
+    /**
+     * The graph of the sliding circuits of the conjugacy class of self
+     */
+    pub fn sliding_circuits_graph(&self) -> SummitGraph {
+        self.as_garside_form().sliding_circuits_graph()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::braid::structure::{
+            ClassicalBraids,
+            GarsideStructure,
+        },
+    };
+
+    #[test]
+    fn cyclic_sliding_tests() {
+        let x = Braid::from_sigmas(&[1, -3, 2, 2, 1, 3], 4).as_garside_form();
+        let p = x.preferred_prefix();
+        assert!(p.is_left_divisor_of(&x.cycling_conjugator()));
+        assert_eq!(
+            x.cyclic_sliding(),
+            x.conjugate(&GarsideForm::from_simple(p))
+        );
+
+        // Sliding never lowers the infimum or raises the supremum
+        let y = x.cyclic_sliding();
+        assert!(y.inf() >= x.inf());
+        assert!(y.sup() <= x.sup());
+
+        let (z, c) = x.sliding_circuit_element();
+        assert_eq!(x.conjugate(&c), z);
+        assert!(z.is_in_sliding_circuits());
+        assert!(z.is_in_ultra_summit_set());
+    }
+
+    #[test]
+    fn sliding_pullback_tests() {
+        let simples = ClassicalBraids::new(4).simples();
+        for word in [
+            &[1, 1, -2, 3, 3, 1, 2][..],
+            &[2, 1, 3, 2, 1, 1, -3][..],
+            &[1, 2, -3, 2, 2][..],
+        ] {
+            let (x, _) = Braid::from_sigmas(word, 4)
+                .as_garside_form()
+                .super_summit_element();
+            let in_sss = |z: &GarsideForm, s: &SimpleBraid| {
+                let y = z.conjugate(&GarsideForm::from_simple(s.clone()));
+                y.inf() == z.inf() && y.sup() == z.sup()
+            };
+            let sx = x.cyclic_sliding();
+            for s in simples.iter().filter(|s| in_sss(&x, s)) {
+                let t = x.sliding_transport(s);
+                let y = x.conjugate(&GarsideForm::from_simple(s.clone()));
+                assert_eq!(
+                    sx.conjugate(&GarsideForm::from_simple(t)),
+                    y.cyclic_sliding()
+                );
+            }
+            for t in simples.iter().filter(|t| in_sss(&sx, t)) {
+                let b = x.sliding_pullback(t);
+                for s in simples.iter().filter(|s| in_sss(&x, s)) {
+                    assert_eq!(
+                        b.is_left_divisor_of(s),
+                        t.is_left_divisor_of(&x.sliding_transport(s))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_sliding_conjugator_tests() {
+        let simples = ClassicalBraids::new(4).simples();
+        for word in [
+            &[1, 1, -2, 3, 3, 1, 2][..],
+            &[1, 2, -3, 2, 2][..],
+            &[2, 1, 3, 2, 1, 1][..],
+        ] {
+            let (y, _) = Braid::from_sigmas(word, 4)
+                .as_garside_form()
+                .sliding_circuit_element();
+            for i in 1..4 {
+                let u = SimpleBraid::sigma(i, 4);
+                let c = y.minimal_sliding_conjugator(u.clone());
+                assert!(u.is_left_divisor_of(&c));
+                for t in &simples {
+                    let z = y.conjugate(&GarsideForm::from_simple(t.clone()));
+                    if u.is_left_divisor_of(t) && z.is_in_sliding_circuits() {
+                        assert!(c.is_left_divisor_of(t));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sliding_circuits_brute_force_tests() {
+        for (word, n) in [
+            (&[1, 2, -3, 2, 2][..], 4),
+            (&[1, 1, -2, 3, 3, 1, 2][..], 4),
+            (&[2, 1, 3, 2, 1, 1][..], 4),
+            (&[1, 2, 3, 4, -1, 2, 2, -3][..], 5),
+            (&[1, 1, 2, -4, 3, 3, 2, 1, -2][..], 5),
+        ] {
+            let x = Braid::from_sigmas(word, n);
+            let brute: Vec<GarsideForm> = x
+                .super_summit_set()
+                .into_iter()
+                .filter(|z| z.sliding_orbit().last().unwrap().cyclic_sliding() == *z)
+                .collect();
+            let sc = x.sliding_circuits();
+            assert_eq!(sc.len(), brute.len());
+            assert!(brute.iter().all(|z| sc.contains(z)));
+            assert!(sc.len() <= x.ultra_summit_set().len());
+        }
+    }
+}
@@ -0,0 +1,935 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+};
+
+use crate::{
+    braid::{
+        garside::GarsideForm,
+        simple::{
+            compose_permutations,
+            invert_permutation,
+            sigma_permutation,
+            SimpleBraid,
+        },
+        *,
+    },
+    permutation::*,
+};
+
+/*
+ * Garside structures. A Garside group is determined by its lattice of
+ * simple elements: the divisors of the Garside element delta, with the
+ * automorphism tau(s) = delta^-1 s delta, meets on both sides and the
+ * complements s^-1 delta and delta s^-1. Everything else here (normal
+ * forms, cycling, decycling and super summit sets) only uses those, so it
+ * is written once for any structure. The classical braid monoid is one
+ * structure, and GarsideForm leaves its arithmetic to it, and the dual
+ * braid monoid (see dual.rs) is another.
+ * Conjugation is written as in summit.rs: x^c = c^-1 x c
+ */
+
+/**
+ * The lattice of simple elements of a Garside group. Implementations only
+ * have to compute on simple elements, and may assume that the arguments
+ * satisfy the stated divisibility conditions
+ */
+pub trait GarsideStructure: Clone + PartialEq + Eq + Hash + fmt::Debug {
+    type Simple: Clone + PartialEq + Eq + Hash + fmt::Debug;
+
+    fn identity(&self) -> Self::Simple;
+
+    fn delta(&self) -> Self::Simple;
+
+    /**
+     * The simple elements with no proper divisors other than the identity
+     */
+    fn atoms(&self) -> Vec<Self::Simple>;
+
+    /**
+     * The number of atoms in any word for s
+     */
+    fn simple_len(&self, s: &Self::Simple) -> usize;
+
+    /**
+     * delta^-1 s delta
+     */
+    fn tau(&self, s: &Self::Simple) -> Self::Simple;
+
+    /**
+     * delta s delta^-1
+     */
+    fn tau_inverse(&self, s: &Self::Simple) -> Self::Simple;
+
+    /**
+     * Some k > 0 with tau^k the identity
+     */
+    fn tau_order(&self) -> usize;
+
+    /**
+     * The greatest common prefix of a and b
+     */
+    fn left_meet(&self, a: &Self::Simple, b: &Self::Simple) -> Self::Simple;
+
+    /**
+     * The greatest common suffix of a and b
+     */
+    fn right_meet(&self, a: &Self::Simple, b: &Self::Simple) -> Self::Simple;
+
+    /**
+     * The simple element c with s c = delta
+     */
+    fn right_complement(&self, s: &Self::Simple) -> Self::Simple;
+
+    /**
+     * The simple element c with c s = delta
+     */
+    fn left_complement(&self, s: &Self::Simple) -> Self::Simple;
+
+    /**
+     * a^-1 b, for a a prefix of b
+     */
+    fn quotient(&self, a: &Self::Simple, b: &Self::Simple) -> Self::Simple;
+
+    fn is_identity(&self, s: &Self::Simple) -> bool {
+        *s == self.identity()
+    }
+
+    fn is_delta(&self, s: &Self::Simple) -> bool {
+        *s == self.delta()
+    }
+
+    /**
+     * tau^k(s), for any integer k
+     */
+    fn tau_power(&self, s: &Self::Simple, k: isize) -> Self::Simple {
+        let k = k.rem_euclid(self.tau_order() as isize);
+        (0..k).fold(s.clone(), |s, _| self.tau(&s))
+    }
+
+    /**
+     * Is a a prefix of b?
+     */
+    fn is_left_divisor(&self, a: &Self::Simple, b: &Self::Simple) -> bool {
+        self.left_meet(a, b) == *a
+    }
+
+    /**
+     * The product a b, if it is simple: exactly when b is a prefix of the
+     * right complement of a
+     */
+    fn product(&self, a: &Self::Simple, b: &Self::Simple) -> Option<Self::Simple> {
+        let complement = self.right_complement(a);
+        self.is_left_divisor(b, &complement)
+            .then(|| self.left_complement(&self.quotient(b, &complement)))
+    }
+
+    /**
+     * The smallest simple element with both a and b as prefixes: the left
+     * complement of the greatest common suffix of their right complements
+     */
+    fn left_join(&self, a: &Self::Simple, b: &Self::Simple) -> Self::Simple {
+        let suffix = self.right_meet(&self.right_complement(a), &self.right_complement(b));
+        self.left_complement(&suffix)
+    }
+
+    /**
+     * a \ b = a^-1 (a v b)
+     */
+    fn residual(&self, a: &Self::Simple, b: &Self::Simple) -> Self::Simple {
+        self.quotient(a, &self.left_join(a, b))
+    }
+
+    /**
+     * Make the pair a b left weighted without changing their product, by
+     * moving the greatest common prefix of b and the right complement of a
+     * from b to a. Returns whether anything moved
+     */
+    fn left_weight(&self, a: &mut Self::Simple, b: &mut Self::Simple) -> bool {
+        let complement = self.right_complement(a);
+        let c = self.left_meet(&complement, b);
+        if self.is_identity(&c) {
+            return false;
+        }
+        // a c is the left complement of c^-1 (a^-1 delta)
+        *a = self.left_complement(&self.quotient(&c, &complement));
+        *b = self.quotient(&c, b);
+        true
+    }
+
+    /**
+     * Every simple element, built up from the identity by multiplying by
+     * atoms. There are usually exponentially many
+     */
+    fn simples(&self) -> Vec<Self::Simple> {
+        let atoms = self.atoms();
+        let mut res = vec![self.identity()];
+        let mut i = 0;
+        while i < res.len() {
+            for a in &atoms {
+                if let Some(next) = self.product(&res[i], a)
+                    && !res.contains(&next)
+                {
+                    res.push(next);
+                }
+            }
+            i += 1;
+        }
+        res
+    }
+}
+
+/**
+ * The left normal form delta^k A_1 ... A_r of an element of a Garside
+ * group, where each A_i is a simple element other than the identity and
+ * delta, and each A_i A_i+1 is left weighted
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalForm<G: GarsideStructure> {
+    pub(crate) structure: G,
+    pub(crate) delta_exp: isize,
+    pub(crate) factors: Vec<G::Simple>,
+}
+
+/**
+ * A left normal form under construction. Factors are stored up to a power of
+ * tau: factor i is really tau^(twist - twists[i])(factors[i]). Moving a delta
+ * from the middle to the front applies tau to everything before it, and this
+ * lets that be done by bumping one counter and the (usually very short) suffix
+ * instead of rewriting the whole prefix
+ */
+struct FormBuilder<'a, G: GarsideStructure> {
+    form: &'a mut NormalForm<G>,
+    twists: Vec<usize>,
+    twist: usize,
+}
+
+impl<'a, G: GarsideStructure> FormBuilder<'a, G> {
+    fn new(form: &'a mut NormalForm<G>) -> Self {
+        let twists = vec![0; form.factors.len()];
+        Self {
+            form,
+            twists,
+            twist: 0,
+        }
+    }
+
+    /**
+     * Rewrite factor i so that it is stored as its real value
+     */
+    fn materialize(&mut self, i: usize) {
+        let order = self.form.structure.tau_order();
+        let k = (self.twist + order - self.twists[i]) % order;
+        if k != 0 {
+            let a = &mut self.form.factors[i];
+            *a = self.form.structure.tau_power(a, k as isize);
+            self.twists[i] = self.twist;
+        }
+    }
+
+    /**
+     * Multiply on the right by a simple element, sweeping right to left.
+     * When the sweep produces a delta, the rest of the sweep would only
+     * carry it to the front, so that is done directly
+     */
+    fn push(&mut self, factor: G::Simple) {
+        self.form.factors.push(factor);
+        self.twists.push(self.twist);
+        let mut i = self.form.factors.len() - 1;
+        while i > 0 {
+            self.materialize(i - 1);
+            self.materialize(i);
+            let (head, tail) = self.form.factors.split_at_mut(i);
+            if !self.form.structure.left_weight(&mut head[i - 1], &mut tail[0]) {
+                break;
+            }
+            if self.form.structure.is_delta(&self.form.factors[i - 1]) {
+                // A_1 ... A_i-2 delta Y = delta tau(A_1 ... A_i-2) Y
+                self.form.factors.remove(i - 1);
+                self.twists.remove(i - 1);
+                self.form.delta_exp += 1;
+                let order = self.form.structure.tau_order();
+                self.twist = (self.twist + 1) % order;
+                for t in &mut self.twists[i - 1..] {
+                    *t = (*t + 1) % order;
+                }
+                break;
+            }
+            i -= 1;
+        }
+        // The identity and delta are both fixed by tau
+        while self
+            .form
+            .factors
+            .last()
+            .is_some_and(|a| self.form.structure.is_identity(a))
+        {
+            self.form.factors.pop();
+            self.twists.pop();
+        }
+        if self
+            .form
+            .factors
+            .first()
+            .is_some_and(|a| self.form.structure.is_delta(a))
+        {
+            self.form.factors.remove(0);
+            self.twists.remove(0);
+            self.form.delta_exp += 1;
+        }
+    }
+
+    fn finish(mut self) {
+        for i in 0..self.form.factors.len() {
+            self.materialize(i);
+        }
+    }
+}
+
+impl<G: GarsideStructure> NormalForm<G> {
+    pub fn identity(structure: G) -> Self {
+        Self {
+            structure,
+            delta_exp: 0,
+            factors: vec![],
+        }
+    }
+
+    /**
+     * The normal form of a single simple element
+     */
+    pub fn from_simple(structure: G, s: G::Simple) -> Self {
+        let mut res = Self::identity(structure);
+        res.push_factor(s);
+        res
+    }
+
+    /**
+     * delta^k
+     */
+    pub fn delta_power(structure: G, k: isize) -> Self {
+        let mut res = Self::identity(structure);
+        res.delta_exp = k;
+        res
+    }
+
+    pub fn structure(&self) -> &G {
+        &self.structure
+    }
+
+    /**
+     * The largest k such that delta^k is a left divisor
+     */
+    pub fn inf(&self) -> isize {
+        self.delta_exp
+    }
+
+    /**
+     * The smallest k such that delta^k is a right multiple
+     */
+    pub fn sup(&self) -> isize {
+        self.delta_exp + self.canonical_length() as isize
+    }
+
+    /**
+     * The number of non-delta simple factors
+     */
+    pub fn canonical_length(&self) -> usize {
+        self.factors.len()
+    }
+
+    pub fn factors(&self) -> &[G::Simple] {
+        &self.factors
+    }
+
+    /**
+     * After sliding, factors equal to delta can only appear at the start
+     * and identity factors only at the end
+     */
+    fn absorb_trivial_factors(&mut self) {
+        while self
+            .factors
+            .last()
+            .is_some_and(|a| self.structure.is_identity(a))
+        {
+            self.factors.pop();
+        }
+        let deltas = self
+            .factors
+            .iter()
+            .take_while(|a| self.structure.is_delta(a))
+            .count();
+        self.factors.drain(..deltas);
+        self.delta_exp += deltas as isize;
+    }
+
+    /**
+     * Multiply on the right by a simple element, left weighting it against
+     * the factors before it from right to left and stopping as soon as a
+     * pair is unchanged (see FormBuilder)
+     * O(r) lattice operations worst case where r is the canonical length
+     */
+    pub fn push_factor(&mut self, factor: G::Simple) {
+        let mut builder = FormBuilder::new(self);
+        builder.push(factor);
+        builder.finish();
+    }
+
+    /**
+     * Multiply on the left by a simple element, left weighting from left
+     * to right and stopping as soon as a pair is unchanged
+     * O(r) lattice operations worst case where r is the canonical length
+     */
+    pub fn prepend_factor(&mut self, factor: G::Simple) {
+        // factor delta^p = delta^p tau^p(factor)
+        let factor = self.structure.tau_power(&factor, self.delta_exp);
+        self.factors.insert(0, factor);
+        for i in 0..self.factors.len() - 1 {
+            let (head, tail) = self.factors.split_at_mut(i + 1);
+            if !self.structure.left_weight(&mut head[i], &mut tail[0]) {
+                break;
+            }
+        }
+        self.absorb_trivial_factors();
+    }
+
+    /**
+     * Multiply on the right by delta^k, using A delta^k = delta^k tau^k(A)
+     * O(r) tau powers
+     */
+    pub fn push_delta_power(&mut self, k: isize) {
+        for a in &mut self.factors {
+            *a = self.structure.tau_power(a, k);
+        }
+        self.delta_exp += k;
+    }
+
+    /**
+     * Multiply on the right by the inverse of a simple element, using
+     * s^-1 = (s^-1 delta) delta^-1
+     */
+    pub fn push_inverse_factor(&mut self, factor: &G::Simple) {
+        self.push_factor(self.structure.right_complement(factor));
+        self.push_delta_power(-1);
+    }
+
+    /**
+     * Multiply on the left by the inverse of a simple element, using
+     * s^-1 = delta^-1 (delta s^-1)
+     */
+    pub fn prepend_inverse_factor(&mut self, factor: &G::Simple) {
+        self.prepend_factor(self.structure.left_complement(factor));
+        self.delta_exp -= 1;
+    }
+
+    /**
+     * The normal form of the inverse. Writing A^-1 = (A^-1 delta) delta^-1
+     * and moving every delta^-1 to the front gives
+     * delta^-(p + r) C_r ... C_1 with C_i = tau^-(p + i)(A_i^-1 delta),
+     * which is already left weighted
+     * O(r) complements and tau powers
+     */
+    pub fn inverse(&self) -> Self {
+        let factors = self
+            .factors
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, a)| {
+                let k = self.delta_exp + i as isize + 1;
+                self.structure.tau_power(&self.structure.right_complement(a), -k)
+            })
+            .collect();
+        Self {
+            structure: self.structure.clone(),
+            delta_exp: -(self.delta_exp + self.factors.len() as isize),
+            factors,
+        }
+    }
+
+    /**
+     * x^c = c^-1 x c
+     */
+    pub fn conjugate(&self, c: &Self) -> Self {
+        c.inverse() * self.clone() * c.clone()
+    }
+
+    /**
+     * The simple element tau^-p(A_1) which cycling conjugates by, or the
+     * identity if there are no factors
+     */
+    pub fn cycling_conjugator(&self) -> G::Simple {
+        match self.factors.first() {
+            Some(a) => self.structure.tau_power(a, -self.delta_exp),
+            None => self.structure.identity(),
+        }
+    }
+
+    /**
+     * Cycling: delta^p A_1 ... A_r -> delta^p A_2 ... A_r tau^-p(A_1),
+     * which is the conjugate by tau^-p(A_1)
+     */
+    pub fn cycling(&self) -> Self {
+        let mut res = self.clone();
+        if res.factors.is_empty() {
+            return res;
+        }
+        res.factors.remove(0);
+        res.push_factor(self.cycling_conjugator());
+        res
+    }
+
+    /**
+     * Decycling: delta^p A_1 ... A_r -> A_r delta^p A_1 ... A_r-1,
+     * which is the conjugate by A_r^-1
+     */
+    pub fn decycling(&self) -> Self {
+        let mut res = self.clone();
+        if let Some(last) = res.factors.pop() {
+            res.prepend_factor(last);
+        }
+        res
+    }
+
+    /**
+     * Conjugate into the super summit set by iterated cycling and then
+     * iterated decycling. If as many cyclings in a row as there are atoms in
+     * delta do not raise the infimum then it is maximal, and likewise for
+     * decycling and the supremum. Returns y and c with y = c^-1 self c
+     */
+    pub fn super_summit_element(&self) -> (Self, Self) {
+        self.super_summit_element_within(|| false).unwrap()
+    }
+
+    /**
+     * super_summit_element, giving up with None as soon as stop returns
+     * true. It is called before every cycling and decycling
+     */
+    pub(crate) fn super_summit_element_within(
+        &self,
+        mut stop: impl FnMut() -> bool,
+    ) -> Option<(Self, Self)> {
+        let bound = self.structure.simple_len(&self.structure.delta());
+        let mut y = self.clone();
+        let mut c = Self::identity(self.structure.clone());
+
+        let mut tries = 0;
+        while tries < bound && !y.factors.is_empty() {
+            if stop() {
+                return None;
+            }
+            let step = Self::from_simple(self.structure.clone(), y.cycling_conjugator());
+            let next = y.cycling();
+            c = c * step;
+            if next.delta_exp > y.delta_exp {
+                tries = 0;
+            } else {
+                tries += 1;
+            }
+            y = next;
+        }
+
+        let mut tries = 0;
+        while tries < bound && !y.factors.is_empty() {
+            if stop() {
+                return None;
+            }
+            let step = Self::from_simple(self.structure.clone(), y.factors.last().unwrap().clone());
+            let next = y.decycling();
+            c = c * step.inverse();
+            if next.sup() < y.sup() {
+                tries = 0;
+            } else {
+                tries += 1;
+            }
+            y = next;
+        }
+
+        Some((y, c))
+    }
+
+    /**
+     * The smallest s' with s a prefix of s' and inf(self^s') >= inf(self).
+     * For self = delta^p a this means tau^p(s') is a prefix of a s', and
+     * any such s' has a \ tau^p(s') as a prefix as well
+     */
+    fn inf_closure(&self, mut s: G::Simple) -> G::Simple {
+        let structure = &self.structure;
+        loop {
+            let u = structure.tau_power(&s, self.delta_exp);
+            let t = self.factors.iter().fold(u, |u, a| structure.residual(a, &u));
+            if structure.is_left_divisor(&t, &s) {
+                return s;
+            }
+            s = structure.left_join(&s, &t);
+        }
+    }
+
+    /**
+     * For self in its super summit set, the smallest simple element with s
+     * as a prefix which conjugates self to another element of the super
+     * summit set (Franco and Gonzalez-Meneses)
+     */
+    pub fn minimal_summit_conjugator(&self, mut s: G::Simple) -> G::Simple {
+        let inverse = self.inverse();
+        loop {
+            let s1 = self.inf_closure(s);
+            let s2 = inverse.inf_closure(s1.clone());
+            if s2 == s1 {
+                return s1;
+            }
+            s = s2;
+        }
+    }
+
+    /**
+     * The super summit set together with a conjugator from self to each
+     * element, explored along the minimal simple conjugators of the atoms.
+     * Stops early once stop returns true for an element
+     */
+    fn explore_super_summit_set(&self, mut stop: impl FnMut(&Self) -> bool) -> Vec<(Self, Self)> {
+        let (root, c) = self.super_summit_element();
+        let mut seen = HashMap::new();
+        seen.insert(root.clone(), 0);
+        let mut res = vec![(root, c)];
+        let mut i = 0;
+        while i < res.len() {
+            if stop(&res[i].0) {
+                break;
+            }
+            let (y, c) = res[i].clone();
+            for a in self.structure.atoms() {
+                let s = Self::from_simple(self.structure.clone(), y.minimal_summit_conjugator(a));
+                let z = y.conjugate(&s);
+                if !seen.contains_key(&z) {
+                    seen.insert(z.clone(), res.len());
+                    res.push((z, c.clone() * s));
+                }
+            }
+            i += 1;
+        }
+        res
+    }
+
+    /**
+     * The super summit set: the conjugates with the largest infimum and
+     * smallest supremum, in the order they were found
+     */
+    pub fn super_summit_set(&self) -> Vec<Self> {
+        self.explore_super_summit_set(|_| false)
+            .into_iter()
+            .map(|(y, _)| y)
+            .collect()
+    }
+
+    /**
+     * Decide whether other is a conjugate of self, and if it is
+     * return c with other = c^-1 self c
+     */
+    pub fn conjugator_to(&self, other: &Self) -> Option<Self> {
+        if self.structure != other.structure {
+            return None;
+        }
+        let (y, cy) = other.super_summit_element();
+        let found = self.explore_super_summit_set(|z| *z == y);
+        found
+            .into_iter()
+            .find(|(z, _)| *z == y)
+            .map(|(_, c)| c * cy.inverse())
+    }
+}
+
+impl<G: GarsideStructure> std::ops::Mul for NormalForm<G> {
+    type Output = Self;
+
+    /**
+     * delta^p A_1 ... A_r delta^q B_1 ... B_s
+     * = delta^(p + q) tau^q(A_1) ... tau^q(A_r) B_1 ... B_s
+     */
+    fn mul(self, other: Self) -> Self {
+        debug_assert_eq!(
+            self.structure, other.structure,
+            "Attempted to compose elements of different groups!"
+        );
+
+        let mut res = self;
+        res.push_delta_power(other.delta_exp);
+        for a in other.factors {
+            res.push_factor(a);
+        }
+        res
+    }
+}
+
+/**
+ * The classical Garside structure on B_n, whose simple elements are the
+ * permutation braids and whose atoms are the sigma_i
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClassicalBraids {
+    pub n: usize,
+}
+
+impl ClassicalBraids {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+impl GarsideStructure for ClassicalBraids {
+    type Simple = SimpleBraid;
+
+    fn identity(&self) -> SimpleBraid {
+        SimpleBraid::identity(self.n)
+    }
+
+    fn delta(&self) -> SimpleBraid {
+        SimpleBraid::delta(self.n)
+    }
+
+    fn atoms(&self) -> Vec<SimpleBraid> {
+        (1..self.n).map(|i| SimpleBraid::sigma(i, self.n)).collect()
+    }
+
+    fn simple_len(&self, s: &SimpleBraid) -> usize {
+        s.crossings()
+    }
+
+    fn tau(&self, s: &SimpleBraid) -> SimpleBraid {
+        s.tau()
+    }
+
+    fn tau_inverse(&self, s: &SimpleBraid) -> SimpleBraid {
+        s.tau()
+    }
+
+    fn tau_order(&self) -> usize {
+        2
+    }
+
+    fn left_meet(&self, a: &SimpleBraid, b: &SimpleBraid) -> SimpleBraid {
+        a.left_meet(b)
+    }
+
+    fn right_meet(&self, a: &SimpleBraid, b: &SimpleBraid) -> SimpleBraid {
+        a.right_meet(b)
+    }
+
+    fn right_complement(&self, s: &SimpleBraid) -> SimpleBraid {
+        s.right_complement()
+    }
+
+    fn left_complement(&self, s: &SimpleBraid) -> SimpleBraid {
+        s.left_complement()
+    }
+
+    fn quotient(&self, a: &SimpleBraid, b: &SimpleBraid) -> SimpleBraid {
+        SimpleBraid::from_permutation(compose_permutations(
+            &invert_permutation(a.permutation()),
+            b.permutation(),
+        ))
+    }
+
+    fn is_identity(&self, s: &SimpleBraid) -> bool {
+        s.is_identity()
+    }
+
+    fn is_delta(&self, s: &SimpleBraid) -> bool {
+        s.is_twist()
+    }
+
+    /**
+     * Checks S(b) against F(a) first and takes a single meet, instead of
+     * the complements and products of the generic version
+     */
+    fn left_weight(&self, a: &mut SimpleBraid, b: &mut SimpleBraid) -> bool {
+        a.left_weight(b)
+    }
+}
+
+impl NormalForm<ClassicalBraids> {
+    /**
+     * Multiply on the right by a single Artin generator
+     */
+    pub fn push_generator(&mut self, g: BrGen) {
+        match g {
+            BrGen::Sigma(i) => self.push_factor(SimpleBraid::sigma(i, self.structure.n)),
+            BrGen::SigmaInv(i) => self.push_inverse_factor(&SimpleBraid::sigma(i, self.structure.n)),
+        }
+    }
+
+    /**
+     * Multiply on the left by a single Artin generator
+     */
+    pub fn prepend_generator(&mut self, g: BrGen) {
+        match g {
+            BrGen::Sigma(i) => self.prepend_factor(SimpleBraid::sigma(i, self.structure.n)),
+            BrGen::SigmaInv(i) => {
+                self.prepend_inverse_factor(&SimpleBraid::sigma(i, self.structure.n))
+            }
+        }
+    }
+}
+
+impl From<GarsideForm> for NormalForm<ClassicalBraids> {
+    fn from(x: GarsideForm) -> Self {
+        Self {
+            structure: ClassicalBraids::new(x.n),
+            delta_exp: x.delta_exp,
+            factors: x
+                .permutations
+                .into_iter()
+                .map(SimpleBraid::from_permutation)
+                .collect(),
+        }
+    }
+}
+
+impl From<NormalForm<ClassicalBraids>> for GarsideForm {
+    fn from(x: NormalForm<ClassicalBraids>) -> Self {
+        Self {
+            delta_exp: x.delta_exp,
+            permutations: x.factors.into_iter().map(|s| s.into_vec()).collect(),
+            n: x.structure.n,
+        }
+    }
+}
+
+impl Braid {
+    /**
+     * The left normal form in the generic classical structure, which
+     * as_garside_form is built on.
+     * The word is read left to right. Each sigma_i^-1 is rewritten as
+     * tau(B_i) delta_n^-1 with B_i = delta_n sigma_i^-1, and instead of moving
+     * that delta_n^-1 to the front (which would rewrite the whole prefix) it is
+     * moved to the end, applying tau to every later generator. Only the parity
+     * of the number of inverses seen so far is needed for this. The positive
+     * word that results is gathered into simple factors with permutation
+     * arithmetic and pushed onto the normal form one at a time, and finally
+     * Q delta_n^-m = delta_n^-m tau^m(Q)
+     * O(L * n) for reading the word, plus O(n log n) for every pair of factors
+     * re-weighted while pushing and O(n) for the pair each push stops at.
+     * Pushes usually only touch the last few factors, and never more than the
+     * r factors of the canonical length, so O(L * r * n log n) worst case
+     */
+    pub fn as_normal_form(&self) -> NormalForm<ClassicalBraids> {
+        let n = self.n;
+        let mut res = NormalForm::identity(ClassicalBraids::new(n));
+        let mut builder = FormBuilder::new(&mut res);
+        // The simple factor currently being built from positive generators
+        let mut current = VecPermutation::id(n);
+        let mut inverses: isize = 0;
+
+        for g in &self.gens {
+            let (i, inverse) = match g {
+                BrGen::Sigma(i) => (*i, false),
+                BrGen::SigmaInv(i) => (*i, true),
+            };
+            let i = if inverses % 2 == 0 { i } else { n - i };
+            if inverse {
+                let factor = std::mem::replace(&mut current, VecPermutation::id(n));
+                builder.push(SimpleBraid::from_permutation(factor));
+                builder.push(SimpleBraid::sigma(i, n).left_complement().tau());
+                inverses += 1;
+            } else if current[i - 1] < current[i] {
+                // Strands at i and i + 1 have not crossed yet, so this stays simple
+                current.swap(i - 1, i);
+            } else {
+                let factor = std::mem::replace(&mut current, sigma_permutation(i, n));
+                builder.push(SimpleBraid::from_permutation(factor));
+            }
+        }
+        builder.push(SimpleBraid::from_permutation(current));
+        builder.finish();
+        res.push_delta_power(-inverses);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use {
+        super::*,
+        crate::braid::dual::DualBraids,
+    };
+
+    #[test]
+    fn classical_structure_tests() {
+        let structure = ClassicalBraids::new(4);
+        assert_eq!(structure.simples().len(), 24);
+        let s1 = SimpleBraid::sigma(1, 4);
+        let s2 = SimpleBraid::sigma(2, 4);
+        assert_eq!(structure.left_join(&s1, &s2), s1.left_join(&s2));
+        assert_eq!(structure.residual(&s1, &s2), s1.residual(&s2));
+        assert_eq!(structure.product(&s1, &s2), s1.try_mul(&s2));
+        assert_eq!(structure.product(&s1, &s1), None);
+
+        for _ in 0..20 {
+            let x = Braid::random_positive(5, 3, 4, 0.1) * Braid::random_positive(5, 2, 4, 0.1).inverse();
+            let y = Braid::random_positive(5, 3, 4, 0.1) * Braid::random_positive(5, 2, 4, 0.1).inverse();
+            let (gx, nx) = (x.as_garside_form(), x.as_normal_form());
+            assert_eq!(GarsideForm::from(nx.clone()), gx);
+            // Reading the word with the tau parity agrees with one generator at a time
+            let mut pushed = NormalForm::identity(ClassicalBraids::new(5));
+            for g in x.iter() {
+                pushed.push_generator(*g);
+            }
+            assert_eq!(pushed, nx);
+            assert_eq!(NormalForm::from(gx.clone()), nx);
+            assert_eq!(GarsideForm::from(nx.inverse()), gx.inverse());
+            assert_eq!(
+                GarsideForm::from(nx.clone() * y.as_normal_form()),
+                gx.clone() * y.as_garside_form()
+            );
+            assert_eq!(GarsideForm::from(nx.cycling()), gx.cycling());
+            assert_eq!(GarsideForm::from(nx.decycling()), gx.decycling());
+        }
+    }
+
+    #[test]
+    fn push_delta_tests() {
+        // Pushing delta carries it through every factor, applying tau to each
+        let x = Braid::from_sigmas(&[1, 2, 2, 3, -1, 4, 4, 3, -2], 5);
+        let mut form = x.as_normal_form();
+        let mut expected = form.clone();
+        expected.push_delta_power(1);
+        form.push_factor(SimpleBraid::delta(5));
+        assert_eq!(form, expected);
+
+        // In the dual structure tau has order n rather than 2
+        let structure = DualBraids::new(5);
+        let mut form = x.as_dual_form();
+        let mut expected = form.clone();
+        for k in 1..=6 {
+            form.push_factor(structure.delta());
+            expected.push_delta_power(1);
+            assert_eq!(form, expected, "{}", k);
+        }
+    }
+
+    #[test]
+    fn generic_super_summit_tests() {
+        for _ in 0..5 {
+            let x = Braid::random_positive(4, 2, 3, 0.1) * Braid::random_positive(4, 1, 3, 0.1).inverse();
+            let generic: HashSet<GarsideForm> = x
+                .as_normal_form()
+                .super_summit_set()
+                .into_iter()
+                .map(GarsideForm::from)
+                .collect();
+            let classical: HashSet<GarsideForm> = x.super_summit_set().into_iter().collect();
+            assert_eq!(generic, classical);
+
+            let a = Braid::random_positive(4, 2, 3, 0.1) * Braid::from_sigmas(&[-2, 1, -3], 4);
+            let y = (a.inverse() * x.clone() * a).as_normal_form();
+            let c = x.as_normal_form().conjugator_to(&y).unwrap();
+            assert_eq!(x.as_normal_form().conjugate(&c), y);
+        }
+        let x = Braid::from_sigmas(&[1, 2], 4).as_normal_form();
+        let y = Braid::from_sigmas(&[1, 3], 4).as_normal_form();
+        assert!(x.conjugator_to(&y).is_none());
+    }
+}
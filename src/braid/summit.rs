@@ -0,0 +1,516 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use crate::braid::{
+    garside::GarsideForm,
+    simple::SimpleBraid,
+    structure::NormalForm,
+    *,
+};
+
+/*
+ * Conjugation is written as in Elrifai Morton: x^c = c^-1 x c,
+ * so the conjugating elements returned by the GarsideForm methods
+ * here satisfy y = c^-1 x c
+ */
+
+/**
+ * The finite subset of a conjugacy class which conjugacy is decided on.
+ * Each is contained in the one before it: smaller sets take fewer
+ * conjugations to search, at the cost of more work per conjugation
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ConjugacyBackend {
+    SuperSummit,
+    UltraSummit,
+    #[default]
+    SlidingCircuits,
+}
+
+impl GarsideForm {
+    /**
+     * The normal form of a single simple braid
+     */
+    pub fn from_simple(s: SimpleBraid) -> Self {
+        let mut res = Self::identity(s.n());
+        res.push_factor(s.into_vec());
+        res
+    }
+
+    /**
+     * x^c = c^-1 x c
+     */
+    pub fn conjugate(&self, c: &Self) -> Self {
+        c.inverse() * self.clone() * c.clone()
+    }
+
+    /**
+     * The simple braid tau^p(A_1) which cycling conjugates by, or the
+     * identity if there are no factors
+     */
+    pub fn cycling_conjugator(&self) -> SimpleBraid {
+        match self.simple_factors().next() {
+            Some(a) if self.delta_exp % 2 != 0 => a.tau(),
+            Some(a) => a,
+            None => SimpleBraid::identity(self.n),
+        }
+    }
+
+    /**
+     * Cycling: delta_n^p A_1 ... A_r -> delta_n^p A_2 ... A_r tau^-p(A_1),
+     * which is the conjugate by tau^p(A_1)
+     * O(r * n^2)
+     */
+    pub fn cycling(&self) -> Self {
+        NormalForm::from(self.clone()).cycling().into()
+    }
+
+    /**
+     * Decycling: delta_n^p A_1 ... A_r -> A_r delta_n^p A_1 ... A_r-1,
+     * which is the conjugate by A_r^-1
+     * O(r * n^2)
+     */
+    pub fn decycling(&self) -> Self {
+        NormalForm::from(self.clone()).decycling().into()
+    }
+
+    /**
+     * Conjugate into the super summit set by iterated cycling and then
+     * iterated decycling, with |delta_n| = n(n - 1) / 2 tries in a row as
+     * the bound (Birman Ko Lee, Theorem 3.8).
+     * Returns y and c with y = c^-1 self c
+     */
+    pub fn super_summit_element(&self) -> (Self, Self) {
+        self.super_summit_element_within(|| false).unwrap()
+    }
+
+    /**
+     * super_summit_element, giving up with None as soon as stop returns
+     * true. It is called before every cycling and decycling
+     */
+    pub(crate) fn super_summit_element_within(
+        &self,
+        stop: impl FnMut() -> bool,
+    ) -> Option<(Self, Self)> {
+        let (y, c) = NormalForm::from(self.clone()).super_summit_element_within(stop)?;
+        Some((y.into(), c.into()))
+    }
+
+    /**
+     * self, step(self), step^2(self), ... up to (but not including) the
+     * first repetition, giving up with None as soon as stop returns true.
+     * It is called before every step
+     */
+    pub(crate) fn orbit_within(
+        &self,
+        step: impl Fn(&Self) -> Self,
+        mut stop: impl FnMut() -> bool,
+    ) -> Option<Vec<Self>> {
+        let mut seen = HashSet::new();
+        let mut res = vec![];
+        let mut y = self.clone();
+        while !seen.contains(&y) {
+            if stop() {
+                return None;
+            }
+            seen.insert(y.clone());
+            res.push(y.clone());
+            y = step(&y);
+        }
+        Some(res)
+    }
+
+    /**
+     * For self in its super summit set, the smallest simple braid with s as a
+     * prefix which conjugates self to another element of the super summit
+     * set (see NormalForm::minimal_summit_conjugator)
+     */
+    pub fn minimal_summit_conjugator(&self, s: SimpleBraid) -> SimpleBraid {
+        NormalForm::from(self.clone()).minimal_summit_conjugator(s)
+    }
+
+    /**
+     * The minimal simple conjugators of self along which the super summit
+     * set is explored: rho(sigma_i) for every generator
+     */
+    pub(crate) fn summit_conjugators(&self) -> Vec<SimpleBraid> {
+        let mut res: Vec<SimpleBraid> = vec![];
+        for i in 1..self.n {
+            let rho = self.minimal_summit_conjugator(SimpleBraid::sigma(i, self.n));
+            if !res.contains(&rho) {
+                res.push(rho);
+            }
+        }
+        res
+    }
+
+    /**
+     * The graph of the super summit set of the conjugacy class of self,
+     * with an arrow for every minimal simple conjugator
+     */
+    pub fn super_summit_graph(&self) -> SummitGraph {
+        self.summit_graph(ConjugacyBackend::SuperSummit)
+    }
+
+    /**
+     * The super summit set: the conjugates with the largest infimum and
+     * smallest supremum, which is finite and a complete conjugacy invariant
+     */
+    pub fn super_summit_set(&self) -> Vec<Self> {
+        self.super_summit_graph().into_sorted_vertices()
+    }
+
+    /**
+     * Decide whether other is a conjugate of self, and if it is
+     * return c with other = c^-1 self c
+     */
+    pub fn conjugator_to(&self, other: &Self) -> Option<Self> {
+        self.conjugator_to_with(other, ConjugacyBackend::SuperSummit)
+    }
+
+    /**
+     * An element of the given subset of the conjugacy class of self,
+     * as y and c with y = c^-1 self c
+     */
+    pub fn summit_element(&self, backend: ConjugacyBackend) -> (Self, Self) {
+        match backend {
+            ConjugacyBackend::SuperSummit => self.super_summit_element(),
+            ConjugacyBackend::UltraSummit => self.ultra_summit_element(),
+            ConjugacyBackend::SlidingCircuits => self.sliding_circuit_element(),
+        }
+    }
+
+    /**
+     * summit_element, giving up with None as soon as stop returns true.
+     * It is called before every cycling, decycling and sliding
+     */
+    pub(crate) fn summit_element_within(
+        &self,
+        backend: ConjugacyBackend,
+        stop: impl FnMut() -> bool,
+    ) -> Option<(Self, Self)> {
+        match backend {
+            ConjugacyBackend::SuperSummit => self.super_summit_element_within(stop),
+            ConjugacyBackend::UltraSummit => self.ultra_summit_element_within(stop),
+            ConjugacyBackend::SlidingCircuits => self.sliding_circuit_element_within(stop),
+        }
+    }
+
+    pub(crate) fn summit_arrows(backend: ConjugacyBackend) -> fn(&Self) -> Vec<SimpleBraid> {
+        match backend {
+            ConjugacyBackend::SuperSummit => Self::summit_conjugators,
+            ConjugacyBackend::UltraSummit => Self::ultra_conjugators,
+            ConjugacyBackend::SlidingCircuits => Self::sliding_conjugators,
+        }
+    }
+
+    /**
+     * The graph of the given subset of the conjugacy class of self
+     */
+    pub fn summit_graph(&self, backend: ConjugacyBackend) -> SummitGraph {
+        let (y, _) = self.summit_element(backend);
+        SummitGraph::explore(y, Self::summit_arrows(backend), |_| false)
+    }
+
+    /**
+     * Decide whether other is a conjugate of self by searching the given
+     * subset of the conjugacy class, and if it is return c with
+     * other = c^-1 self c
+     */
+    pub fn conjugator_to_with(&self, other: &Self, backend: ConjugacyBackend) -> Option<Self> {
+        if self.n != other.n {
+            return None;
+        }
+        let (x, cx) = self.summit_element(backend);
+        let (y, cy) = other.summit_element(backend);
+        if x.delta_exp != y.delta_exp || x.sup() != y.sup() {
+            return None;
+        }
+        let graph = SummitGraph::explore(x, Self::summit_arrows(backend), |z| *z == y);
+        // y = d^-1 x d, x = cx^-1 self cx and y = cy^-1 other cy
+        graph
+            .conjugator_from_root(&y)
+            .map(|d| cx * d.clone() * cy.inverse())
+    }
+}
+
+/**
+ * A set of conjugates closed under conjugation by the given simple braids,
+ * with the conjugating simple braids as arrows. The vertices are numbered in
+ * the order they were found, starting from the root at 0
+ */
+#[derive(Debug, Clone)]
+pub struct SummitGraph {
+    vertices: Vec<GarsideForm>,
+    index: HashMap<GarsideForm, usize>,
+    // c with vertices[i] = c^-1 vertices[0] c
+    from_root: Vec<GarsideForm>,
+    // (i, j, s) with vertices[j] = s^-1 vertices[i] s
+    arrows: Vec<(usize, usize, SimpleBraid)>,
+}
+
+impl SummitGraph {
+    /**
+     * Breadth first search from root along the simple braids given by
+     * conjugators. Stops early when stop returns true for a newly found
+     * vertex, in which case the graph is only partial
+     */
+    pub(crate) fn explore(
+        root: GarsideForm,
+        mut conjugators: impl FnMut(&GarsideForm) -> Vec<SimpleBraid>,
+        mut stop: impl FnMut(&GarsideForm) -> bool,
+    ) -> Self {
+        let n = root.n();
+        let mut res = Self {
+            vertices: vec![root.clone()],
+            index: HashMap::new(),
+            from_root: vec![GarsideForm::identity(n)],
+            arrows: vec![],
+        };
+        res.index.insert(root.clone(), 0);
+        if stop(&root) {
+            return res;
+        }
+
+        let mut i = 0;
+        while i < res.vertices.len() {
+            let z = res.vertices[i].clone();
+            for rho in conjugators(&z) {
+                let next = z.conjugate(&GarsideForm::from_simple(rho.clone()));
+                if let Some(&j) = res.index.get(&next) {
+                    res.arrows.push((i, j, rho));
+                    continue;
+                }
+                let j = res.vertices.len();
+                let done = stop(&next);
+                res.from_root
+                    .push(res.from_root[i].clone() * GarsideForm::from_simple(rho.clone()));
+                res.index.insert(next.clone(), j);
+                res.vertices.push(next);
+                res.arrows.push((i, j, rho));
+                if done {
+                    return res;
+                }
+            }
+            i += 1;
+        }
+
+        res
+    }
+
+    /**
+     * The number of vertices
+     */
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /**
+     * The number of arrows
+     */
+    pub fn arrow_count(&self) -> usize {
+        self.arrows.len()
+    }
+
+    pub fn vertices(&self) -> &[GarsideForm] {
+        &self.vertices
+    }
+
+    /**
+     * The arrows (i, j, s) with vertices[j] = s^-1 vertices[i] s
+     */
+    pub fn arrows(&self) -> &[(usize, usize, SimpleBraid)] {
+        &self.arrows
+    }
+
+    pub fn contains(&self, x: &GarsideForm) -> bool {
+        self.index.contains_key(x)
+    }
+
+    /**
+     * c with x = c^-1 root c, if x is a vertex
+     */
+    pub fn conjugator_from_root(&self, x: &GarsideForm) -> Option<&GarsideForm> {
+        self.index.get(x).map(|&i| &self.from_root[i])
+    }
+
+    /**
+     * For every arrow (i, j, s) the braid c_i s c_j^-1, where c_i is the
+     * conjugator from the root to vertices[i]. These conjugate the root to
+     * itself, and the arrows of the search tree give the identity
+     */
+    pub fn loops(&self) -> impl Iterator<Item = GarsideForm> + '_ {
+        self.arrows.iter().map(|(i, j, s)| {
+            self.from_root[*i].clone()
+                * GarsideForm::from_simple(s.clone())
+                * self.from_root[*j].inverse()
+        })
+    }
+
+    pub(crate) fn into_sorted_vertices(self) -> Vec<GarsideForm> {
+        let mut res = self.vertices;
+        res.sort_by(|a, b| a.permutations.cmp(&b.permutations));
+        res
+    }
+}
+
+impl Braid {
+    /**
+     * The super summit set of the conjugacy class of self
+     */
+    pub fn super_summit_set(&self) -> Vec<GarsideForm> {
+        self.as_garside_form().super_summit_set()
+    }
+
+    /**
+     * Decide whether other is conjugate to self, searching the sliding
+     * circuits. If it is, return a braid c with other = c self c^-1 (so c
+     * plays the role of s in s p s^-1)
+     */
+    pub fn is_conjugate(&self, other: &Self) -> Option<Self> {
+        self.is_conjugate_with(other, ConjugacyBackend::default())
+    }
+
+    /**
+     * is_conjugate, searching the given subset of the conjugacy class
+     */
+    pub fn is_conjugate_with(&self, other: &Self, backend: ConjugacyBackend) -> Option<Self> {
+        self.as_garside_form()
+            .conjugator_to_with(&other.as_garside_form(), backend)
+            .map(|c| c.inverse().to_braid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::braid::structure::{
+            ClassicalBraids,
+            GarsideStructure,
+        },
+    };
+
+    #[test]
+    fn cycling_decycling_tests() {
+        let x = Braid::from_sigmas(&[1, -3, 2, 2, 1, 3], 4);
+        let form = x.as_garside_form();
+
+        let c = GarsideForm::from_simple(form.cycling_conjugator());
+        assert_eq!(form.cycling(), form.conjugate(&c));
+
+        let last = GarsideForm::from_simple(form.simple_factors().last().unwrap());
+        assert_eq!(form.decycling(), form.conjugate(&last.inverse()));
+
+        // sigma_1 sigma_1 sigma_2 sigma_2 is conjugate to the shorter sigma_1 sigma_2 sigma_2 sigma_1
+        let y = Braid::from_sigmas(&[1, 1, 2, 2], 3).as_garside_form();
+        assert_eq!(y.canonical_length(), 3);
+        assert_eq!(y.cycling().canonical_length(), 2);
+    }
+
+    #[test]
+    fn super_summit_set_tests() {
+        let sss = Braid::from_sigmas(&[1], 3).super_summit_set();
+        assert_eq!(sss.len(), 2);
+
+        let sss = Braid::from_sigmas(&[1, 2, -1], 4).super_summit_set();
+        for y in &sss {
+            assert_eq!(y.inf(), 0);
+            assert_eq!(y.sup(), 1);
+        }
+        // Every generator is conjugate to every other
+        assert_eq!(sss.len(), 3);
+
+        let x = Braid::from_sigmas(&[1, 1, 2, -3, 2, 2, -1, 3], 4);
+        let (y, c) = x.as_garside_form().super_summit_element();
+        assert_eq!(x.as_garside_form().conjugate(&c), y);
+        for z in x.super_summit_set() {
+            assert_eq!(z.inf(), y.inf());
+            assert_eq!(z.sup(), y.sup());
+        }
+    }
+
+    #[test]
+    fn super_summit_set_brute_force_tests() {
+        // Closing under conjugation by every simple braid (Elrifai Morton)
+        // gives the same set as using only the minimal conjugators
+        let simples: Vec<GarsideForm> = ClassicalBraids::new(4)
+            .simples()
+            .into_iter()
+            .map(GarsideForm::from_simple)
+            .collect();
+
+        for word in [&[1, 2, -3, 2, 2][..], &[1, 1, -2, 3, 3, 1, 2][..], &[2, 1, 3, 2, 1, 1][..]] {
+            let x = Braid::from_sigmas(word, 4);
+            let (y, _) = x.as_garside_form().super_summit_element();
+            let mut brute = vec![y.clone()];
+            let mut i = 0;
+            while i < brute.len() {
+                for c in &simples {
+                    let z = brute[i].conjugate(c);
+                    if z.inf() == y.inf() && z.sup() == y.sup() && !brute.contains(&z) {
+                        brute.push(z);
+                    }
+                }
+                i += 1;
+            }
+            let sss = x.super_summit_set();
+            assert_eq!(sss.len(), brute.len());
+            assert!(brute.iter().all(|z| sss.contains(z)));
+        }
+    }
+
+    #[test]
+    fn is_conjugate_tests() {
+        let a = Braid::from_sigmas(&[1], 4);
+        let b = Braid::from_sigmas(&[3], 4);
+        let c = a.is_conjugate(&b).unwrap();
+        assert!((c.clone() * a.clone() * c.inverse()).is_equivalent(&b));
+
+        assert!(a.is_conjugate(&Braid::from_sigmas(&[1, 1], 4)).is_none());
+        assert!(
+            Braid::from_sigmas(&[1, 2], 4)
+                .is_conjugate(&Braid::from_sigmas(&[1, 3], 4))
+                .is_none()
+        );
+
+        for _ in 0..3 {
+            let x = Braid::random_positive(5, 3, 4, 0.1) * Braid::from_sigmas(&[-2], 5);
+            let s = Braid::random_positive(5, 2, 4, 0.1) * Braid::random_positive(5, 1, 4, 0.1).inverse();
+            let y = s.clone() * x.clone() * s.inverse();
+            let c = x.is_conjugate(&y).unwrap();
+            assert!((c.clone() * x.clone() * c.inverse()).is_equivalent(&y));
+        }
+    }
+
+    #[test]
+    fn conjugacy_backend_tests() {
+        let backends = [
+            ConjugacyBackend::SuperSummit,
+            ConjugacyBackend::UltraSummit,
+            ConjugacyBackend::SlidingCircuits,
+        ];
+        let x = Braid::random_positive(5, 3, 4, 0.1) * Braid::from_sigmas(&[-2, -4], 5);
+        let s = Braid::random_positive(5, 2, 4, 0.1) * Braid::random_positive(5, 1, 4, 0.1).inverse();
+        let y = s.clone() * x.clone() * s.inverse();
+        let mut sizes = vec![];
+        for backend in backends {
+            let c = x.is_conjugate_with(&y, backend).unwrap();
+            assert!((c.clone() * x.clone() * c.inverse()).is_equivalent(&y));
+            assert!(x.is_conjugate_with(&(x.clone() * x.clone()), backend).is_none());
+
+            let graph = x.as_garside_form().summit_graph(backend);
+            let (z, _) = y.as_garside_form().summit_element(backend);
+            assert!(graph.contains(&z));
+            sizes.push(graph.len());
+        }
+        // Sliding circuits lie in the ultra summit set, which lies in the super summit set
+        assert!(sizes[0] >= sizes[1] && sizes[1] >= sizes[2]);
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{
+    braid::{
+        artin::CoxeterGroup,
+        structure::NormalForm,
+        *,
+    },
+    permutation::*,
+};
+
+/*
+ * The Artin group of type B_n, generated by t, sigma_1, ..., sigma_(n - 1)
+ * with t sigma_1 t sigma_1 = sigma_1 t sigma_1 t, t commuting with the
+ * other sigma_i and the usual braid relations between those. It is the
+ * group of annular braids: sending t to sigma_1^2 and sigma_i to
+ * sigma_(i + 1) identifies it with the braids on n + 1 strands whose first
+ * strand is pure. Its classical Garside structure has the signed
+ * permutations as simple elements, ordered by the weak order, and
+ * delta = (t sigma_1 ... sigma_(n - 1))^n as the longest element, which is
+ * central
+ */
+
+/**
+ * A generator of the Artin group of type B_n or its inverse
+ */
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TypeBGen {
+    T,
+    TInv,
+    Sigma(usize),
+    SigmaInv(usize),
+}
+
+impl TypeBGen {
+    pub fn inverse(&self) -> Self {
+        match *self {
+            Self::T => Self::TInv,
+            Self::TInv => Self::T,
+            Self::Sigma(i) => Self::SigmaInv(i),
+            Self::SigmaInv(i) => Self::Sigma(i),
+        }
+    }
+
+    /**
+     * The index of the Coxeter generator, with t as 0, and whether this is
+     * the positive generator
+     */
+    fn index(&self) -> (usize, bool) {
+        match *self {
+            Self::T => (0, true),
+            Self::TInv => (0, false),
+            Self::Sigma(i) => (i, true),
+            Self::SigmaInv(i) => (i, false),
+        }
+    }
+}
+
+impl From<BrGen> for TypeBGen {
+    fn from(g: BrGen) -> Self {
+        match g {
+            BrGen::Sigma(i) => Self::Sigma(i),
+            BrGen::SigmaInv(i) => Self::SigmaInv(i),
+        }
+    }
+}
+
+/**
+ * An element of the Artin group of type B_n as a word in the generators
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeBBraid {
+    pub gens: Vec<TypeBGen>,
+    // Our braid is an element of the group of type B_n
+    pub n: usize,
+}
+
+impl std::ops::Mul for TypeBBraid {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        debug_assert_eq!(
+            self.n, other.n,
+            "Attempted to compose two different sized braids!"
+        );
+
+        Self {
+            gens: self.gens.into_iter().chain(other.gens).collect(),
+            n: self.n,
+        }
+    }
+}
+
+impl TypeBBraid {
+    pub fn from_gens(gens: &[TypeBGen], n: usize) -> Self {
+        Self {
+            gens: gens.to_vec(),
+            n,
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            gens: self.gens.iter().rev().map(|g| g.inverse()).collect(),
+            n: self.n,
+        }
+    }
+
+    /**
+     * The annular braid on n + 1 strands: t is sigma_1^2 and sigma_i is
+     * sigma_(i + 1). This is injective, so it can be used to check results
+     * in B_(n + 1)
+     */
+    pub fn to_braid(&self) -> Braid {
+        let gens = self
+            .gens
+            .iter()
+            .flat_map(|g| match *g {
+                TypeBGen::T => vec![BrGen::Sigma(1); 2],
+                TypeBGen::TInv => vec![BrGen::SigmaInv(1); 2],
+                TypeBGen::Sigma(i) => vec![BrGen::Sigma(i + 1)],
+                TypeBGen::SigmaInv(i) => vec![BrGen::SigmaInv(i + 1)],
+            })
+            .collect();
+        Braid { gens, n: self.n + 1 }
+    }
+
+    /**
+     * The left normal form of this element
+     * O(L * r * n^2) where r is the canonical length
+     */
+    pub fn as_normal_form(&self) -> TypeBForm {
+        let mut res = TypeBForm::identity(TypeB::new(self.n));
+        for g in &self.gens {
+            res.push_generator(*g);
+        }
+        res
+    }
+
+    /**
+     * Are self and other the same element? Decided by comparing normal forms
+     */
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.n == other.n && self.as_normal_form() == other.as_normal_form()
+    }
+}
+
+/**
+ * The classical Garside structure on the Artin group of type B_n, whose
+ * simple elements are the signed permutations and whose atoms are t and the
+ * sigma_i
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeB {
+    pub n: usize,
+}
+
+impl TypeB {
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+/**
+ * s_0 is t and s_i is sigma_i
+ */
+impl CoxeterGroup for TypeB {
+    type Element = SignedPermutation;
+
+    fn rank(&self) -> usize {
+        self.n
+    }
+
+    fn identity_element(&self) -> SignedPermutation {
+        SignedPermutation::identity(self.n)
+    }
+
+    fn longest_element(&self) -> SignedPermutation {
+        SignedPermutation::longest(self.n)
+    }
+
+    fn simple_reflection(&self, i: usize) -> SignedPermutation {
+        SignedPermutation::generator(i, self.n)
+    }
+
+    fn compose(&self, a: &SignedPermutation, b: &SignedPermutation) -> SignedPermutation {
+        a.compose(b)
+    }
+
+    fn inverse(&self, w: &SignedPermutation) -> SignedPermutation {
+        w.inverse()
+    }
+
+    fn length(&self, w: &SignedPermutation) -> usize {
+        w.length()
+    }
+
+    fn is_left_descent(&self, w: &SignedPermutation, i: usize) -> bool {
+        w.is_left_descent(i)
+    }
+}
+
+/**
+ * The left normal form delta^k A_1 ... A_r of an element of the Artin
+ * group of type B_n
+ */
+pub type TypeBForm = NormalForm<TypeB>;
+
+impl TypeBForm {
+    pub fn n(&self) -> usize {
+        self.structure.n
+    }
+
+    /**
+     * Multiply on the right by a single generator
+     */
+    pub fn push_generator(&mut self, g: TypeBGen) {
+        let (i, positive) = g.index();
+        let s = SignedPermutation::generator(i, self.n());
+        if positive {
+            self.push_factor(s);
+        } else {
+            self.push_inverse_factor(&s);
+        }
+    }
+
+    /**
+     * The word of this form: delta^k followed by a reduced word for each
+     * factor. Equal forms always give the same word
+     */
+    pub fn to_type_b_braid(&self) -> TypeBBraid {
+        let n = self.n();
+        let word = |s: &SignedPermutation| -> Vec<TypeBGen> {
+            s.reduced_word()
+                .into_iter()
+                .map(|i| if i == 0 { TypeBGen::T } else { TypeBGen::Sigma(i) })
+                .collect()
+        };
+        let delta = TypeBBraid::from_gens(&word(&SignedPermutation::longest(n)), n);
+        let delta = if self.delta_exp < 0 {
+            delta.inverse()
+        } else {
+            delta
+        };
+        let gens = (0..self.delta_exp.unsigned_abs())
+            .flat_map(|_| delta.gens.iter().cloned())
+            .chain(self.factors.iter().flat_map(word))
+            .collect();
+        TypeBBraid { gens, n }
+    }
+}
+
+impl Braid {
+    /**
+     * The same word in the Artin group of type B_n, where the sigma_i
+     * generate a copy of B_n
+     */
+    pub fn to_type_b_braid(&self) -> TypeBBraid {
+        TypeBBraid {
+            gens: self.iter().map(|&g| g.into()).collect(),
+            n: self.n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::braid::structure::GarsideStructure;
+
+    use TypeBGen::*;
+
+    fn random_type_b(n: usize, len: usize) -> TypeBBraid {
+        let x = Braid::random_positive(n, 2, len, 0.1) * Braid::random_positive(n, 2, len, 0.1).inverse();
+        // Put some t^+-1 between the sigma_i
+        let mut gens: Vec<TypeBGen> = vec![];
+        for (k, g) in x.iter().enumerate() {
+            gens.push((*g).into());
+            if k % 3 == 0 {
+                gens.push(if k % 2 == 0 { T } else { TInv });
+            }
+        }
+        TypeBBraid::from_gens(&gens, n)
+    }
+
+    #[test]
+    fn type_b_relation_tests() {
+        let n = 3;
+        let b = |gens: &[TypeBGen]| TypeBBraid::from_gens(gens, n);
+        assert!(b(&[T, Sigma(1), T, Sigma(1)]).is_equivalent(&b(&[Sigma(1), T, Sigma(1), T])));
+        assert!(b(&[T, Sigma(2)]).is_equivalent(&b(&[Sigma(2), T])));
+        assert!(b(&[Sigma(1), Sigma(2), Sigma(1)]).is_equivalent(&b(&[Sigma(2), Sigma(1), Sigma(2)])));
+        assert!(!b(&[T, Sigma(1), T]).is_equivalent(&b(&[Sigma(1), T, Sigma(1)])));
+        assert!(b(&[T, TInv, Sigma(2), SigmaInv(2)]).as_normal_form() == TypeBForm::identity(TypeB::new(n)));
+
+        // delta = (t sigma_1 ... sigma_(n - 1))^n is central with n^2 letters
+        let coxeter = b(&[T, Sigma(1), Sigma(2)]);
+        let delta = coxeter.clone() * coxeter.clone() * coxeter;
+        let form = delta.as_normal_form();
+        assert_eq!((form.inf(), form.canonical_length()), (1, 0));
+        for g in [T, Sigma(1), Sigma(2)] {
+            let x = b(&[g]);
+            assert!((delta.clone() * x.clone()).is_equivalent(&(x * delta.clone())));
+        }
+        assert_eq!(TypeB::new(n).simples().len(), 48);
+    }
+
+    #[test]
+    fn type_b_normal_form_tests() {
+        let n = 4;
+        for _ in 0..20 {
+            let x = random_type_b(n, 3);
+            let y = random_type_b(n, 3);
+            let (fx, fy) = (x.as_normal_form(), y.as_normal_form());
+            // Compare with the annular braids in B_(n + 1)
+            assert_eq!(fx == fy, x.to_braid().is_equivalent(&y.to_braid()));
+            assert!(fx.to_type_b_braid().to_braid().is_equivalent(&x.to_braid()));
+            assert_eq!(fx.to_type_b_braid().as_normal_form(), fx);
+            assert_eq!(fx.inverse(), x.inverse().as_normal_form());
+            assert_eq!(fx.clone() * fy, (x.clone() * y).as_normal_form());
+        }
+
+        let x = random_type_b(3, 2);
+        let a = random_type_b(3, 2);
+        let y = (a.inverse() * x.clone() * a).as_normal_form();
+        let c = x.as_normal_form().conjugator_to(&y).unwrap();
+        assert_eq!(x.as_normal_form().conjugate(&c), y);
+    }
+}
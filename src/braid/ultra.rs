@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+
+use crate::braid::{
+    garside::GarsideForm,
+    simple::{
+        word_residual,
+        SimpleBraid,
+    },
+    summit::{
+        ConjugacyBackend,
+        SummitGraph,
+    },
+    *,
+};
+
+/*
+ * Gebhardt's ultra summit set: the elements of the super summit set which
+ * come back to themselves under iterated cycling. It is usually much smaller
+ * than the super summit set, and is again explored through minimal simple
+ * conjugators, which are found by transporting conjugators along the
+ * cycling orbit. Conjugation is x^c = c^-1 x c as in summit.rs
+ */
+
+/**
+ * The product of simple braids whose product is known to be simple
+ */
+pub(crate) fn word_to_simple(word: Vec<SimpleBraid>, n: usize) -> SimpleBraid {
+    word.into_iter().fold(SimpleBraid::identity(n), |s, a| {
+        s.try_mul(&a).expect("expected a simple product")
+    })
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl GarsideForm {
+    /**
+     * self, c(self), c^2(self), ... up to (but not including) the first
+     * repetition. For self in its ultra summit set this is the whole orbit
+     */
+    pub fn cycling_orbit(&self) -> Vec<Self> {
+        self.orbit_within(Self::cycling, || false).unwrap()
+    }
+
+    /**
+     * Is self in the super summit set of its conjugacy class and
+     * periodic under cycling?
+     */
+    pub fn is_in_ultra_summit_set(&self) -> bool {
+        let (y, _) = self.super_summit_element();
+        if y.delta_exp != self.delta_exp || y.sup() != self.sup() {
+            return false;
+        }
+        let orbit = self.cycling_orbit();
+        orbit.last().unwrap().cycling() == *self
+    }
+
+    /**
+     * Conjugate into the ultra summit set: go to the super summit set, then
+     * cycle until an element repeats. Returns y and c with y = c^-1 self c
+     */
+    pub fn ultra_summit_element(&self) -> (Self, Self) {
+        self.ultra_summit_element_within(|| false).unwrap()
+    }
+
+    /**
+     * ultra_summit_element, giving up with None as soon as stop returns
+     * true. It is called before every cycling and decycling
+     */
+    pub(crate) fn ultra_summit_element_within(
+        &self,
+        mut stop: impl FnMut() -> bool,
+    ) -> Option<(Self, Self)> {
+        let (x, cx) = self.super_summit_element_within(&mut stop)?;
+        let orbit = x.orbit_within(Self::cycling, &mut stop)?;
+        // The first repeated element is the cycling of the last one
+        let y = orbit.last().unwrap().cycling();
+        let mut c = cx;
+        for z in &orbit {
+            if *z == y {
+                break;
+            }
+            c = c * Self::from_simple(z.cycling_conjugator());
+        }
+        Some((y, c))
+    }
+
+    /**
+     * The simple braid with this normal form, for forms known to be simple
+     */
+    pub(crate) fn to_simple(&self) -> SimpleBraid {
+        match (self.delta_exp, self.permutations.len()) {
+            (0, 0) => SimpleBraid::identity(self.n),
+            (0, 1) => SimpleBraid::from_permutation(self.permutations[0].clone()),
+            (1, 0) => SimpleBraid::delta(self.n),
+            _ => panic!("expected a simple braid, got {}", self),
+        }
+    }
+
+    /**
+     * The transport of s: for self in its super summit set and self^s in
+     * it as well, the simple braid s' with c(self)^s' = c(self^s), given by
+     * s' = tau^p(A_1)^-1 s tau^p(B_1) where self^s = delta_n^p B_1 ...
+     */
+    pub fn transport(&self, s: &SimpleBraid) -> SimpleBraid {
+        let s_form = Self::from_simple(s.clone());
+        let y = self.conjugate(&s_form);
+        let t = Self::from_simple(self.cycling_conjugator()).inverse()
+            * s_form
+            * Self::from_simple(y.cycling_conjugator());
+        t.to_simple()
+    }
+
+    /**
+     * For s conjugating self within its super summit set, with
+     * self^s = delta_n^p B_1 ..., p t is a prefix of s tau^p(B_1) exactly
+     * when the simple braid returned here is a prefix of s.
+     * Writing self = delta_n^p X, s tau^p(B_1) = tau^p(X s) ^ (s delta_n), so
+     * this is (X \ tau^p(p t)) v (delta_n \ tau(p t))
+     * O(r * n^2)
+     */
+    pub(crate) fn initial_factor_bound(&self, p: &SimpleBraid, t: &SimpleBraid) -> SimpleBraid {
+        let tau_p = |s: &SimpleBraid| {
+            if self.delta_exp % 2 == 0 {
+                s.clone()
+            } else {
+                s.tau()
+            }
+        };
+        let factors: Vec<SimpleBraid> = self.simple_factors().collect();
+        let from_factors = word_residual(&factors, &[tau_p(p), tau_p(t)]);
+        let from_delta = word_residual(&[SimpleBraid::delta(self.n)], &[p.tau(), t.tau()]);
+        word_to_simple(from_factors, self.n).left_join(&word_to_simple(from_delta, self.n))
+    }
+
+    /**
+     * The pullback of t: for self in its super summit set and t a conjugator
+     * of c(self) within it, the smallest s conjugating self within its super
+     * summit set whose transport has t as a prefix. With A the cycling
+     * conjugator, that is A t being a prefix of s tau^p(B_1)
+     */
+    pub fn pullback(&self, t: &SimpleBraid) -> SimpleBraid {
+        let bound = self.initial_factor_bound(&self.cycling_conjugator(), t);
+        self.minimal_summit_conjugator(bound)
+    }
+
+    /**
+     * The first entries of the sequence s, f(s), f(f(s)), ... up to (but not
+     * including) the first repetition, and the index the repetition goes back to
+     */
+    fn eventual_cycle(
+        s: SimpleBraid,
+        f: impl Fn(SimpleBraid) -> SimpleBraid,
+    ) -> (Vec<SimpleBraid>, usize) {
+        let mut seen = HashMap::new();
+        let mut res = vec![];
+        let mut t = s;
+        while !seen.contains_key(&t) {
+            seen.insert(t.clone(), res.len());
+            res.push(t.clone());
+            t = f(t);
+        }
+        let start = seen[&t];
+        (res, start)
+    }
+
+    /**
+     * For self periodic under an operation with transport F and pullback B
+     * around the orbit of self, the smallest simple braid with u as a prefix
+     * which is periodic under F. Let P be the period of the answer t*.
+     * If s is a prefix of t* then so are F^kP(s) and, as B is adjoint to F,
+     * B^kP(s). Choosing k so that both sequences have become periodic,
+     * joining s with F^kP(s) and B^kP(s) either grows s or shows that
+     * s = F^kP(s) is periodic and hence equal to t*
+     */
+    pub(crate) fn minimal_periodic_conjugator(
+        &self,
+        u: SimpleBraid,
+        forward: impl Fn(SimpleBraid) -> SimpleBraid,
+        backward: impl Fn(SimpleBraid) -> SimpleBraid,
+    ) -> SimpleBraid {
+        let mut s = self.minimal_summit_conjugator(u);
+        loop {
+            let (fs, fj) = Self::eventual_cycle(s.clone(), &forward);
+            if fj == 0 {
+                return s;
+            }
+            // Joining with the forward transport alone usually suffices,
+            // the pullbacks are only needed when it does not grow s
+            let fq = fs.len() - fj;
+            let f = &fs[fj.div_ceil(fq) * fq];
+            if !f.is_left_divisor_of(&s) {
+                s = self.minimal_summit_conjugator(s.left_join(f));
+                continue;
+            }
+            let (bs, bj) = Self::eventual_cycle(s.clone(), &backward);
+            let bq = bs.len() - bj;
+            let period = fq / gcd(fq, bq) * bq;
+            let k = fj.max(bj).div_ceil(period) * period;
+            let f = &fs[fj + (k - fj) % fq];
+            let b = &bs[bj + (k - bj) % bq];
+            s = self.minimal_summit_conjugator(s.left_join(f).left_join(b));
+        }
+    }
+
+    /**
+     * For self in its ultra summit set, the smallest simple braid with u as
+     * a prefix which conjugates self to another element of the ultra summit
+     * set (Gebhardt). These are exactly the conjugators which are periodic
+     * under transport around the cycling orbit
+     */
+    pub fn minimal_ultra_conjugator(&self, u: SimpleBraid) -> SimpleBraid {
+        let orbit = self.cycling_orbit();
+        self.minimal_periodic_conjugator(
+            u,
+            |s| orbit.iter().fold(s, |s, z| z.transport(&s)),
+            |s| orbit.iter().rev().fold(s, |s, z| z.pullback(&s)),
+        )
+    }
+
+    /**
+     * The minimal elements among the minimal(sigma_i), which are the arrows
+     * out of self in the graph of its conjugates
+     */
+    pub(crate) fn minimal_conjugators(
+        &self,
+        minimal: impl Fn(SimpleBraid) -> SimpleBraid,
+    ) -> Vec<SimpleBraid> {
+        let mut candidates: Vec<SimpleBraid> = vec![];
+        for i in 1..self.n {
+            let c = minimal(SimpleBraid::sigma(i, self.n));
+            if !candidates.contains(&c) {
+                candidates.push(c);
+            }
+        }
+        candidates
+            .iter()
+            .filter(|c| {
+                !candidates
+                    .iter()
+                    .any(|d| d != *c && d.is_left_divisor_of(c))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn ultra_conjugators(&self) -> Vec<SimpleBraid> {
+        self.minimal_conjugators(|u| self.minimal_ultra_conjugator(u))
+    }
+
+    /**
+     * The graph of the ultra summit set of the conjugacy class of self,
+     * with an arrow for every minimal simple conjugator
+     */
+    pub fn ultra_summit_graph(&self) -> SummitGraph {
+        self.summit_graph(ConjugacyBackend::UltraSummit)
+    }
+
+    /**
+     * The ultra summit set: the elements of the super summit set which
+     * are periodic under cycling
+     */
+    pub fn ultra_summit_set(&self) -> Vec<Self> {
+        self.ultra_summit_graph().into_sorted_vertices()
+    }
+
+    /**
+     * Decide whether other is a conjugate of self using ultra summit sets,
+     * and if it is return c with other = c^-1 self c
+     */
+    pub fn ultra_conjugator_to(&self, other: &Self) -> Option<Self> {
+        self.conjugator_to_with(other, ConjugacyBackend::UltraSummit)
+    }
+}
+
+impl Braid {
+    /**
+     * The ultra summit set of the conjugacy class of self
+     */
+    pub fn ultra_summit_set(&self) -> Vec<GarsideForm> {
+        self.as_garside_form().ultra_summit_set()
+    }
+
+    /**
+     * The ultra summit graph of the conjugacy class of self
+     */
+    pub fn ultra_summit_graph(&self) -> SummitGraph {
+        self.as_garside_form().ultra_summit_graph()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::braid::structure::{
+            ClassicalBraids,
+            GarsideStructure,
+        },
+    };
+
+    #[test]
+    fn ultra_summit_element_tests() {
+        let x = Braid::from_sigmas(&[1, 1, 2, -3, 2, 2, -1, 3], 4).as_garside_form();
+        let (y, c) = x.ultra_summit_element();
+        assert_eq!(x.conjugate(&c), y);
+        assert!(y.is_in_ultra_summit_set());
+        assert_eq!(y.cycling_orbit().last().unwrap().cycling(), y);
+
+        // sigma_1 sigma_1 sigma_2 sigma_2 is not in its super summit set
+        assert!(!Braid::from_sigmas(&[1, 1, 2, 2], 3)
+            .as_garside_form()
+            .is_in_ultra_summit_set());
+    }
+
+    #[test]
+    fn transport_tests() {
+        let x = Braid::from_sigmas(&[1, 2, -3, 2, 2, 1], 4).as_garside_form();
+        let (x, _) = x.super_summit_element();
+        for i in 1..4 {
+            let s = x.minimal_summit_conjugator(SimpleBraid::sigma(i, 4));
+            let t = x.transport(&s);
+            let y = x.conjugate(&GarsideForm::from_simple(s));
+            assert_eq!(
+                x.cycling().conjugate(&GarsideForm::from_simple(t)),
+                y.cycling()
+            );
+        }
+    }
+
+    #[test]
+    fn ultra_summit_set_brute_force_tests() {
+        for (word, n) in [
+            (&[1, 2, -3, 2, 2][..], 4),
+            (&[1, 1, -2, 3, 3, 1, 2][..], 4),
+            (&[2, 1, 3, 2, 1, 1][..], 4),
+            (&[1, 2, 3, 4, -1, 2, 2, -3][..], 5),
+            (&[1, 1, 2, -4, 3, 3, 2, 1, -2][..], 5),
+        ] {
+            let x = Braid::from_sigmas(word, n);
+            let brute: Vec<GarsideForm> = x
+                .super_summit_set()
+                .into_iter()
+                .filter(|z| z.cycling_orbit().last().unwrap().cycling() == *z)
+                .collect();
+            let uss = x.ultra_summit_set();
+            assert_eq!(uss.len(), brute.len());
+            assert!(brute.iter().all(|z| uss.contains(z)));
+            assert!(uss.len() <= x.super_summit_set().len());
+        }
+    }
+
+    #[test]
+    fn pullback_tests() {
+        let simples = ClassicalBraids::new(4).simples();
+        for word in [&[1, 1, -2, 3, 3, 1, 2][..], &[2, 1, 3, 2, 1, 1, -3][..]] {
+            let (x, _) = Braid::from_sigmas(word, 4)
+                .as_garside_form()
+                .super_summit_element();
+            let in_sss = |z: &GarsideForm, s: &SimpleBraid| {
+                let y = z.conjugate(&GarsideForm::from_simple(s.clone()));
+                y.inf() == z.inf() && y.sup() == z.sup()
+            };
+            let cx = x.cycling();
+            for t in simples.iter().filter(|t| in_sss(&cx, t)) {
+                let b = x.pullback(t);
+                for s in simples.iter().filter(|s| in_sss(&x, s)) {
+                    assert_eq!(
+                        b.is_left_divisor_of(s),
+                        t.is_left_divisor_of(&x.transport(s))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn minimal_ultra_conjugator_tests() {
+        let simples = ClassicalBraids::new(4).simples();
+        for word in [
+            &[1, 1, -2, 3, 3, 1, 2][..],
+            &[1, 2, -3, 2, 2][..],
+            &[2, 1, 3, 2, 1, 1][..],
+        ] {
+            let (y, _) = Braid::from_sigmas(word, 4)
+                .as_garside_form()
+                .ultra_summit_element();
+            for i in 1..4 {
+                let u = SimpleBraid::sigma(i, 4);
+                let c = y.minimal_ultra_conjugator(u.clone());
+                assert!(u.is_left_divisor_of(&c));
+                assert!(y
+                    .conjugate(&GarsideForm::from_simple(c.clone()))
+                    .is_in_ultra_summit_set());
+                for t in &simples {
+                    if u.is_left_divisor_of(t)
+                        && y.conjugate(&GarsideForm::from_simple(t.clone()))
+                            .is_in_ultra_summit_set()
+                    {
+                        assert!(c.is_left_divisor_of(t));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ultra_conjugator_to_tests() {
+        for _ in 0..3 {
+            let x = Braid::random_positive(5, 3, 4, 0.1) * Braid::from_sigmas(&[-2], 5);
+            let s = Braid::random_positive(5, 2, 4, 0.1)
+                * Braid::random_positive(5, 1, 4, 0.1).inverse();
+            let y = s.clone() * x.clone() * s.inverse();
+            let (x, y) = (x.as_garside_form(), y.as_garside_form());
+            let c = x.ultra_conjugator_to(&y).unwrap();
+            assert_eq!(x.conjugate(&c), y);
+        }
+        let a = Braid::from_sigmas(&[1, 2], 4).as_garside_form();
+        assert!(a
+            .ultra_conjugator_to(&Braid::from_sigmas(&[1, 3], 4).as_garside_form())
+            .is_none());
+    }
+}
@@ -6,14 +6,36 @@ extern crate serde_derive;
 pub mod braid;
 pub mod permutation;
 
-use braid_crypto::prelude::*;
+use std::time::Duration;
+
+use braid_crypto::{
+    braid::{
+        entropy::rank_by_entropy,
+        ko_lee::{
+            KoLeeSolver,
+            SearchBudget,
+        },
+        summit::ConjugacyBackend,
+    },
+    prelude::*,
+};
 
 fn main() {
     println!("running Diffie-Hellman-type integration test");
     println!("computing random braids...");
     let public = Braid::from_sigmas(&[1, 2, 3, 4, 5, 6, 7], 8);
+    println!(
+        "public braid is central: {}, commutes with the left half: {}, centralizer generators: {}",
+        public.is_central(),
+        public.commutes_with_subgroup(4),
+        public.centralizer().len()
+    );
 
-    let mut s_alice = Braid::random_positive(3, 3, 3, 0.1);
+    // Alice keeps whichever of a few candidate secrets mixes the most
+    let candidates: Vec<Braid> = (0..4).map(|_| Braid::random_positive(3, 3, 3, 0.1)).collect();
+    let (best, estimate) = rank_by_entropy(&candidates)[0];
+    println!("secret entropy: {:.4} after {} iterations", estimate.entropy, estimate.iterations);
+    let mut s_alice = candidates[best].clone();
     let mut r_bob = Braid::random_positive(3, 3, 3, 0.1);
 
     s_alice.n = 8;
@@ -42,6 +64,21 @@ fn main() {
     println!();
     println!("{}", k2);
 
+    println!("attacking the public messages...");
+    let budget = SearchBudget {
+        max_steps: None,
+        max_time: Some(Duration::from_secs(10)),
+    };
+    let report = KoLeeSolver::new(ConjugacyBackend::SlidingCircuits, budget).solve(&public, &p_prime);
+    println!("{:?}", report.stats);
+    match report.result {
+        Ok(a) => {
+            let k_eve = a.clone() * p_prime_prime.clone() * a.inverse();
+            println!("recovered the shared key: {}", k_eve.is_equivalent(&k_alice));
+        }
+        Err(e) => println!("attack failed: {}", e),
+    }
+
     println!("All Data:");
     println!("{:?}\n\n{:?}\n\n{:?}", public, k_alice, k_bob);
 }
@@ -1,3 +1,7 @@
+mod signed;
+
+pub use signed::SignedPermutation;
+
 pub type VecPermutation = Vec<usize>;
 
 pub trait Permutation {
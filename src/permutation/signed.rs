@@ -0,0 +1,219 @@
+use crate::permutation::*;
+
+/**
+ * A signed permutation of 1..=n: a permutation w of -n..=n with
+ * w(-i) = -w(i). It is stored as a permutation of 2n points, where the
+ * points 1..=2n stand for -n..=-1, 1..=n, and the entry at position k - 1
+ * is the image of point k. Signed permutations are the Coxeter group of
+ * type B_n, generated by s_0, which negates 1, and the s_i swapping i and
+ * i + 1
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SignedPermutation {
+    perm: VecPermutation,
+}
+
+impl SignedPermutation {
+    fn point(&self, i: isize) -> usize {
+        let n = self.n() as isize;
+        (if i < 0 { n + 1 + i } else { n + i }) as usize
+    }
+
+    fn signed(&self, k: usize) -> isize {
+        let n = self.n() as isize;
+        let k = k as isize;
+        if k <= n { k - n - 1 } else { k - n }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: VecPermutation::id(2 * n),
+        }
+    }
+
+    /**
+     * The signed permutation with the given images of 1..=n, if it is one
+     */
+    pub fn from_window(window: &[isize]) -> Option<Self> {
+        let n = window.len();
+        let mut res = Self::identity(n);
+        let mut seen = vec![false; n];
+        for (i, &w) in window.iter().enumerate() {
+            let a = w.unsigned_abs();
+            if a == 0 || a > n || seen[a - 1] {
+                return None;
+            }
+            seen[a - 1] = true;
+            let (k, image) = (res.point(i as isize + 1), res.point(w));
+            let (k_neg, image_neg) = (res.point(-(i as isize) - 1), res.point(-w));
+            res.perm[k - 1] = image;
+            res.perm[k_neg - 1] = image_neg;
+        }
+        Some(res)
+    }
+
+    /**
+     * The longest element, i -> -i
+     */
+    pub fn longest(n: usize) -> Self {
+        Self {
+            perm: (1..=2 * n).rev().collect(),
+        }
+    }
+
+    /**
+     * The generator s_i, with s_0 the sign change of 1
+     */
+    pub fn generator(i: usize, n: usize) -> Self {
+        debug_assert!(i < n, "s_{} is not a generator of B_{}", i, n);
+        let mut res = Self::identity(n);
+        if i == 0 {
+            res.perm.swap_(n, n + 1);
+        } else {
+            res.perm.swap_(n + i, n + i + 1);
+            res.perm.swap_(n - i, n + 1 - i);
+        }
+        res
+    }
+
+    pub fn n(&self) -> usize {
+        self.perm.len() / 2
+    }
+
+    /**
+     * The image of i, for i in -n..=n other than 0
+     */
+    pub fn image(&self, i: isize) -> isize {
+        self.signed(self.perm[self.point(i) - 1])
+    }
+
+    /**
+     * The images of 1..=n
+     */
+    pub fn window(&self) -> Vec<isize> {
+        (1..=self.n() as isize).map(|i| self.image(i)).collect()
+    }
+
+    /**
+     * The underlying permutation of 2n points
+     */
+    pub fn as_permutation(&self) -> &VecPermutation {
+        &self.perm
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.perm.is_identity()
+    }
+
+    /**
+     * The product self other, which applies other first as functions and
+     * is the product of words read left to right
+     * O(n)
+     */
+    pub fn compose(&self, other: &Self) -> Self {
+        debug_assert_eq!(self.n(), other.n());
+        Self {
+            perm: other.perm.iter().map(|k| self.perm[k - 1]).collect(),
+        }
+    }
+
+    /**
+     * O(n)
+     */
+    pub fn inverse(&self) -> Self {
+        let mut perm = vec![0; self.perm.len()];
+        for (k, image) in self.perm.iter().enumerate() {
+            perm[image - 1] = k + 1;
+        }
+        Self { perm }
+    }
+
+    /**
+     * The Coxeter length: the inversions of the window plus the sum of
+     * the absolute values of its negative entries
+     * O(n^2)
+     */
+    pub fn length(&self) -> usize {
+        let w = self.window();
+        let inversions = (0..w.len())
+            .map(|i| (i + 1..w.len()).filter(|j| w[i] > w[*j]).count())
+            .sum::<usize>();
+        let negatives: usize = w.iter().filter(|x| **x < 0).map(|x| x.unsigned_abs()).sum();
+        inversions + negatives
+    }
+
+    /**
+     * Is self s_i shorter than self?
+     * O(1)
+     */
+    pub fn is_right_descent(&self, i: usize) -> bool {
+        if i == 0 {
+            self.image(1) < 0
+        } else {
+            self.image(i as isize) > self.image(i as isize + 1)
+        }
+    }
+
+    /**
+     * Is s_i self shorter than self?
+     * O(n)
+     */
+    pub fn is_left_descent(&self, i: usize) -> bool {
+        self.inverse().is_right_descent(i)
+    }
+
+    /**
+     * A reduced word for self, as generator indices
+     * O(n * length)
+     */
+    pub fn reduced_word(&self) -> Vec<usize> {
+        let n = self.n();
+        let mut w = self.clone();
+        let mut res = vec![];
+        while let Some(i) = (0..n).find(|i| w.is_left_descent(*i)) {
+            res.push(i);
+            w = Self::generator(i, n).compose(&w);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_permutation_tests() {
+        let w = SignedPermutation::from_window(&[2, -3, 1]).unwrap();
+        assert_eq!(w.window(), vec![2, -3, 1]);
+        assert_eq!(w.image(-2), 3);
+        assert_eq!(w.inverse().window(), vec![3, 1, -2]);
+        assert!(w.compose(&w.inverse()).is_identity());
+        assert!(SignedPermutation::from_window(&[2, -2, 1]).is_none());
+
+        let s0 = SignedPermutation::generator(0, 3);
+        let s1 = SignedPermutation::generator(1, 3);
+        assert_eq!(s0.window(), vec![-1, 2, 3]);
+        // w s_i acts on positions, s_i w on values
+        assert_eq!(w.compose(&s0).window(), vec![-2, -3, 1]);
+        assert_eq!(s1.compose(&w).window(), vec![1, -3, 2]);
+        // (s_0 s_1)^4 = 1
+        let s01 = s0.compose(&s1);
+        assert!(s01.compose(&s01).compose(&s01).compose(&s01).is_identity());
+
+        let longest = SignedPermutation::longest(3);
+        assert_eq!(longest.window(), vec![-1, -2, -3]);
+        assert_eq!(longest.length(), 9);
+        assert_eq!(s0.length(), 1);
+        for i in 0..3 {
+            assert!(longest.is_right_descent(i) && longest.is_left_descent(i));
+        }
+
+        let word = w.reduced_word();
+        assert_eq!(word.len(), w.length());
+        let product = word
+            .iter()
+            .fold(SignedPermutation::identity(3), |x, i| x.compose(&SignedPermutation::generator(*i, 3)));
+        assert_eq!(product, w);
+    }
+}
@@ -1,4 +1,7 @@
-use braid_crypto::braid::*;
+use braid_crypto::braid::{
+    summit::ConjugacyBackend,
+    *,
+};
 
 #[test]
 fn test_garsides_mutations() {
@@ -6,9 +9,7 @@ fn test_garsides_mutations() {
     let mut c = b.clone();
     c.mutate(50);
 
-    let s1 = format!("{}", b.as_garside_form());
-    let s2 = format!("{}", c.as_garside_form());
-    assert_eq!(s1, s2);
+    assert!(b.is_equivalent(&c));
 }
 
 #[test]
@@ -42,9 +43,60 @@ fn test_key_exchange() {
         println!("results:\n{}\n{}", k1, k2);
 
         assert_eq!(k1.to_string(), k2.to_string());
+        assert_eq!(k1.to_braid().as_garside_form(), k1);
     }
 }
 
+#[test]
+fn test_key_exchange_symmetric_form() {
+    let public = Braid::random_positive(15, 5, 2, 0.0);
+
+    let mut s_alice = Braid::random_positive(7, 3, 2, 0.1);
+    let mut r_bob = Braid::random_positive(7, 3, 2, 0.1);
+
+    s_alice.n = 15;
+    r_bob.n = 15;
+
+    r_bob.shift();
+
+    let p_prime = s_alice.clone() * public.clone() * s_alice.inverse();
+    let p_prime_prime = r_bob.clone() * public.clone() * r_bob.inverse();
+
+    let k_alice = s_alice.clone() * p_prime_prime * s_alice.inverse();
+    let k_bob = r_bob.clone() * p_prime * r_bob.inverse();
+
+    let k1 = k_alice.as_symmetric_form();
+    let k2 = k_bob.as_symmetric_form();
+
+    println!("results:\n{}\n{}", k1, k2);
+
+    assert_eq!(k1.to_string(), k2.to_string());
+}
+
+#[test]
+fn test_key_exchange_normal_form() {
+    let public = Braid::random_positive(15, 5, 2, 0.0).as_garside_form();
+
+    let mut s_alice = Braid::random_positive(7, 3, 2, 0.1);
+    let mut r_bob = Braid::random_positive(7, 3, 2, 0.1);
+
+    s_alice.n = 15;
+    r_bob.n = 15;
+
+    r_bob.shift();
+
+    let s_alice = s_alice.as_garside_form();
+    let r_bob = r_bob.as_garside_form();
+
+    let p_prime = s_alice.clone() * public.clone() * s_alice.inverse();
+    let p_prime_prime = r_bob.clone() * public.clone() * r_bob.inverse();
+
+    let k_alice = s_alice.clone() * p_prime_prime * s_alice.inverse();
+    let k_bob = r_bob.clone() * p_prime * r_bob.inverse();
+
+    assert_eq!(k_alice, k_bob);
+}
+
 #[test]
 fn test_slides() {
     let braid1 = Braid::from_sigmas(&[1, -3, 2], 4);
@@ -55,3 +107,32 @@ fn test_slides() {
     println!("{}", gform);
     // outputs: [-1;(4, 3, 1, 2), (2, 3, 1, 4), (3, 1, 4, 2)]
 }
+
+#[test]
+fn test_ultra_summit_graph_key_parameters() {
+    let public = Braid::from_sigmas(
+        &[
+            1, 2, 3, 5, 4, 7, 6, 9, 8, 11, 10, 13, 12, 14, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9,
+            11, 13,
+        ],
+        15,
+    );
+    let mut s_alice = Braid::random_positive(7, 3, 2, 0.1);
+    s_alice.n = 15;
+    let p_prime = s_alice.clone() * public.clone() * s_alice.inverse();
+
+    let graph = public.ultra_summit_graph();
+    println!("ultra summit set: {} elements, {} arrows", graph.len(), graph.arrow_count());
+    assert!(!graph.is_empty());
+    let (y, _) = p_prime.as_garside_form().ultra_summit_element();
+    assert!(graph.contains(&y));
+
+    let c = public.is_conjugate_with(&p_prime, ConjugacyBackend::UltraSummit).unwrap();
+    assert!((c.clone() * public.clone() * c.inverse()).is_equivalent(&p_prime));
+
+    let circuits = public.sliding_circuits_graph();
+    println!("sliding circuits: {} elements, {} arrows", circuits.len(), circuits.arrow_count());
+    assert!(circuits.len() <= graph.len());
+    let c = public.is_conjugate(&p_prime).unwrap();
+    assert!((c.clone() * public.clone() * c.inverse()).is_equivalent(&p_prime));
+}